@@ -0,0 +1,177 @@
+//! Derive macros for Spectral.
+//!
+//! Deriving `Assertable` on a struct generates an extension trait for `Spec`, with a
+//! `field_<name>` method for each named field. Each method returns a new `Spec` for the field,
+//! named after the struct and field so that failure messages report which field failed.
+//! Fields of nested structs are named with the full path to the field, such as
+//! `user_account.owner.email`.
+//!
+//! ```rust,ignore
+//! #[macro_use]
+//! extern crate spectral_derive;
+//!
+//! #[derive(Assertable)]
+//! struct User {
+//!     email: String,
+//! }
+//!
+//! assert_that(&user).field_email().ends_with(&"@corp.com");
+//! ```
+//!
+//! Which will fail with:
+//! ```text
+//!     for subject [user.email]
+//!     expected: string ending with <"@corp.com">
+//!      but was: <"bob@example.com">
+//! ```
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam, Lifetime, LifetimeParam};
+
+/// Generates a `<Struct>FieldAssertions` trait with a `field_<name>` method for each field of the
+/// struct, implemented for `Spec<'s, Struct>`.
+#[proc_macro_derive(Assertable)]
+pub fn derive_assertable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match input.data {
+        Data::Struct(ref data) => {
+            match data.fields {
+                Fields::Named(ref fields) => &fields.named,
+                _ => {
+                    return Err(syn::Error::new_spanned(&input.ident,
+                                                       "Assertable can only be derived for \
+                                                        structs with named fields"))
+                }
+            }
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(&input.ident,
+                                               "Assertable can only be derived for structs"))
+        }
+    };
+
+    let visibility = &input.vis;
+    let struct_name = &input.ident;
+    let trait_name = format_ident!("{}FieldAssertions", struct_name);
+    let subject_prefix = to_snake_case(&struct_name.to_string());
+
+    let spec_lifetime = Lifetime::new("'__spectral_s", Span::call_site());
+
+    // The trait and its implementation take the struct's own generics, plus a lifetime for the
+    // `Spec` which every type parameter must outlive.
+    let mut generics = input.generics.clone();
+    for param in generics.params.iter_mut() {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(syn::TypeParamBound::Lifetime(spec_lifetime.clone()));
+        }
+    }
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(spec_lifetime.clone())));
+
+    let (impl_generics, trait_generics, where_clause) = generics.split_for_impl();
+    let (_, struct_generics, _) = input.generics.split_for_impl();
+
+    let mut declarations = vec![];
+    let mut implementations = vec![];
+
+    for field in fields {
+        let field_name = match field.ident {
+            Some(ref ident) => ident,
+            None => continue,
+        };
+        let field_type = &field.ty;
+
+        let unraw_name = field_name.to_string().trim_start_matches("r#").to_string();
+        let method_name = format_ident!("field_{}", unraw_name);
+        let subject_name = format!("{}.{}", subject_prefix, unraw_name);
+        let doc = format!("Creates a new `Spec` for the `{}` field, named `{}`, or after the \
+                           field's path if this `Spec` already has a subject name.",
+                          unraw_name,
+                          subject_name);
+
+        declarations.push(quote! {
+            #[doc = #doc]
+            fn #method_name(&self) -> ::spectral::Spec<#spec_lifetime, #field_type>;
+        });
+
+        implementations.push(quote! {
+            fn #method_name(&self) -> ::spectral::Spec<#spec_lifetime, #field_type> {
+                // A named `Spec` is usually a field of an outer struct, so the field's name is
+                // appended to it to give the full path to the field.
+                let subject_name = match self.subject_name {
+                    Some(ref parent) => {
+                        ::std::borrow::Cow::Owned(format!("{}.{}", parent, #unraw_name))
+                    }
+                    None => ::std::borrow::Cow::Borrowed(#subject_name),
+                };
+
+                self.field(subject_name, |subject| &subject.#field_name)
+            }
+        });
+    }
+
+    let trait_doc = format!("Assertions for the fields of `{}`, generated by \
+                             `#[derive(Assertable)]`.",
+                            struct_name);
+
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #visibility trait #trait_name #impl_generics #where_clause {
+            #(#declarations)*
+        }
+
+        impl #impl_generics #trait_name #trait_generics
+            for ::spectral::Spec<#spec_lifetime, #struct_name #struct_generics>
+            #where_clause
+        {
+            #(#implementations)*
+        }
+    })
+}
+
+/// Converts a struct name to snake case, treating a run of capitals such as an acronym as a single
+/// word, so that `HTTPStatus` becomes `http_status`.
+fn to_snake_case(name: &str) -> String {
+    let characters: Vec<char> = name.chars().collect();
+    let mut snake_case = String::new();
+
+    for (index, &character) in characters.iter().enumerate() {
+        if character.is_uppercase() {
+            let previous = if index > 0 { Some(characters[index - 1]) } else { None };
+            let next = characters.get(index + 1);
+
+            // A capital starts a new word after a lowercase letter or digit, or at the end of a
+            // run of capitals when it is followed by a lowercase letter.
+            let starts_word = match previous {
+                Some(previous) if previous.is_uppercase() => {
+                    next.is_some_and(|next| next.is_lowercase())
+                }
+                Some(previous) => previous != '_',
+                None => false,
+            };
+
+            if starts_word {
+                snake_case.push('_');
+            }
+            snake_case.extend(character.to_lowercase());
+        } else {
+            snake_case.push(character);
+        }
+    }
+
+    snake_case
+}
@@ -0,0 +1,117 @@
+#[macro_use]
+extern crate spectral_derive;
+extern crate spectral;
+
+use spectral::prelude::*;
+
+#[derive(Assertable, Debug)]
+struct User {
+    email: String,
+    age: u32,
+    nickname: Option<&'static str>,
+}
+
+#[derive(Assertable, Debug)]
+struct UserAccount {
+    owner: User,
+    tags: Vec<String>,
+}
+
+#[derive(Assertable, Debug)]
+struct HTTPStatus {
+    code: u16,
+}
+
+#[derive(Assertable, Debug)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn build_user() -> User {
+    User {
+        email: "bob@corp.com".to_string(),
+        age: 30,
+        nickname: Some("bobby"),
+    }
+}
+
+#[test]
+fn should_not_panic_if_field_assertions_pass() {
+    let user = build_user();
+
+    assert_that(&user).field_email().ends_with("@corp.com");
+    assert_that(&user).field_age().is_greater_than(18);
+    assert_that(&user).field_nickname().is_some().is_equal_to("bobby");
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [user.email]\n\texpected: string ending with \
+                           <\"@example.com\">\n\t but was: <\"bob@corp.com\">")]
+fn should_name_subject_after_struct_and_field() {
+    assert_that(&build_user()).field_email().ends_with("@example.com");
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [user.age]\n\texpected: value less than <18>")]
+fn should_allow_field_assertions_to_be_chained() {
+    let user = build_user();
+
+    assert_that(&user).field_age().is_greater_than(18).is_less_than(18);
+}
+
+fn build_account() -> UserAccount {
+    UserAccount {
+        owner: build_user(),
+        tags: vec![],
+    }
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [user_account.tags]\
+                           \n\texpected: vec to have length <1>")]
+fn should_convert_struct_name_to_snake_case() {
+    assert_that(&build_account()).field_tags().has_length(1);
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [http_status.code]\n\texpected: <404>")]
+fn should_treat_acronym_in_struct_name_as_one_word() {
+    assert_that(&HTTPStatus { code: 200 }).field_code().is_equal_to(404);
+}
+
+#[test]
+fn should_allow_field_assertions_on_nested_structs() {
+    assert_that(&build_account()).field_owner().field_age().is_equal_to(30);
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [user_account.owner.age]\n\texpected: <18>")]
+fn should_name_nested_field_with_full_path() {
+    assert_that(&build_account()).field_owner().field_age().is_equal_to(18);
+}
+
+#[test]
+#[should_panic(expected = "\n\tfor subject [bob.email]\n\texpected: string ending with")]
+fn should_name_field_after_named_subject() {
+    assert_that(&build_user()).named("bob").field_email().ends_with("@example.com");
+}
+
+#[test]
+fn should_generate_field_assertions_trait() {
+    let user = build_user();
+    let spec = assert_that(&user);
+
+    UserFieldAssertions::field_age(&spec).is_equal_to(30);
+}
+
+#[test]
+fn should_support_generic_structs() {
+    let wrapper = Wrapper { value: 5 };
+
+    assert_that(&wrapper).field_value().is_equal_to(5);
+}
+
+#[test]
+fn should_keep_negation_for_field_assertions() {
+    assert_that(&build_user()).not().field_email().ends_with("@example.com");
+}
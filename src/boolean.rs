@@ -1,8 +1,8 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec};
 
 pub trait BooleanAssertions {
-    fn is_true(&mut self);
-    fn is_false(&mut self);
+    fn is_true(&mut self) -> &mut Self;
+    fn is_false(&mut self) -> &mut Self;
 }
 
 impl<'s> BooleanAssertions for Spec<'s, bool> {
@@ -11,13 +11,19 @@ impl<'s> BooleanAssertions for Spec<'s, bool> {
     /// ```rust,ignore
     /// assert_that(&true).is_true();
     /// ```
-    fn is_true(&mut self) {
-        if !*self.subject {
+    #[track_caller]
+    fn is_true(&mut self) -> &mut Self {
+        let subject = *self.subject;
+
+        if !self.passes(subject) {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("bool to be <true>"))
-                .with_actual(format!("<false>"))
+                .with_expected("bool to be <true>".to_string())
+                .with_negated_expected("bool to be <false>".to_string())
+                .with_actual(format!("<{}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is false. The subject type must be `bool`.
@@ -25,13 +31,9 @@ impl<'s> BooleanAssertions for Spec<'s, bool> {
     /// ```rust,ignore
     /// assert_that(&true).is_false();
     /// ```
-    fn is_false(&mut self) {
-        if *self.subject {
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("bool to be <false>"))
-                .with_actual(format!("<true>"))
-                .fail();
-        }
+    #[track_caller]
+    fn is_false(&mut self) -> &mut Self {
+        self.toggle_negation().is_true().toggle_negation()
     }
 }
 
@@ -61,5 +63,10 @@ mod tests {
     pub fn should_panic_if_value_is_expected_to_be_false_and_is_not() {
         assert_that(&true).is_false();
     }
+    #[test]
+    #[should_panic(expected = "\n\texpected: bool to be <false>\n\t but was: <true>")]
+    pub fn should_panic_if_value_is_true_when_negated() {
+        assert_that(&true).not().is_true();
+    }
 
 }
@@ -0,0 +1,244 @@
+use super::{soft, AssertionFailure, DescriptiveSpec, Spec, Subject};
+
+use std::cell::Cell;
+use std::panic;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Held while the panic hook is replaced, so that threads do not replace it at the same time.
+static PANIC_HOOK_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub trait PanicAssertions<'s> {
+    fn panics(&mut self) -> &mut Self;
+    fn does_not_panic(&mut self) -> &mut Self;
+    fn panics_with_message<E: AsRef<str>>(&mut self, expected_message: E) -> &mut Self;
+    fn panic_message(&mut self) -> Spec<'s, String>;
+}
+
+impl<'s, F, R> PanicAssertions<'s> for Spec<'s, F>
+    where F: Fn() -> R
+{
+    /// Asserts that calling the subject closure panics.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&|| parse("")).panics();
+    /// ```
+    #[track_caller]
+    fn panics(&mut self) -> &mut Self {
+        let panic_message = call(&*self.subject);
+
+        if !self.passes(panic_message.is_some()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("closure to panic".to_string())
+                .with_negated_expected("closure to not panic".to_string())
+                .with_actual(describe_outcome(&panic_message))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that calling the subject closure does not panic.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&|| parse("1")).does_not_panic();
+    /// ```
+    #[track_caller]
+    fn does_not_panic(&mut self) -> &mut Self {
+        self.toggle_negation().panics().toggle_negation()
+    }
+
+    /// Asserts that calling the subject closure panics with exactly the expected message.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&|| parse("")).panics_with_message("empty input");
+    /// ```
+    #[track_caller]
+    fn panics_with_message<E: AsRef<str>>(&mut self, expected_message: E) -> &mut Self {
+        let expected_message = expected_message.as_ref();
+        let panic_message = call(&*self.subject);
+
+        if !self.passes(panic_message.as_ref().is_some_and(|message| message == expected_message)) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("closure to panic with message <{:?}>", expected_message))
+                .with_negated_expected(format!("closure to not panic with message <{:?}>",
+                                               expected_message))
+                .with_actual(describe_outcome(&panic_message))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that calling the subject closure panics.
+    ///
+    /// This will return a new `Spec` containing the panic message, so that it can be checked
+    /// with any of the string assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&|| parse("")).panic_message().starts_with("empty");
+    /// ```
+    #[track_caller]
+    fn panic_message(&mut self) -> Spec<'s, String> {
+        self.ensure_not_negated("panic_message");
+
+        match call(&*self.subject) {
+            Some(message) => {
+                Spec {
+                    subject: Subject::Owned(message),
+                    subject_name: self.subject_name.clone(),
+                    location: self.location.clone(),
+                    description: self.description,
+                    negated: false,
+                }
+            }
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected("closure to panic".to_string())
+                    .with_actual("did not panic".to_string())
+                    .fail_and_stop();
+            }
+        }
+    }
+}
+
+/// Calls the closure, returning the panic message if it panicked.
+fn call<F, R>(closure: &F) -> Option<String>
+    where F: Fn() -> R
+{
+    silence_panics(|| soft::catch_panic(closure))
+        .err()
+        .map(|payload| soft::panic_message(&*payload))
+}
+
+/// Runs the provided block with panics on the current thread silenced, so that the panics being
+/// asserted on do not print "thread panicked at" messages. Panics on other threads are passed
+/// through to the previous panic hook, which is restored once the block has run.
+fn silence_panics<F, R>(block: F) -> R
+    where F: FnOnce() -> R
+{
+    if SILENCED.with(Cell::get) {
+        return block();
+    }
+
+    let _lock = PANIC_HOOK_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let previous = Arc::new(panic::take_hook());
+    let hook = Arc::clone(&previous);
+    panic::set_hook(Box::new(move |info| {
+        if !SILENCED.with(Cell::get) {
+            hook(info);
+        }
+    }));
+
+    SILENCED.with(|silenced| silenced.set(true));
+    let result = block();
+    SILENCED.with(|silenced| silenced.set(false));
+
+    // Dropping the silencing hook releases its handle on the previous one.
+    drop(panic::take_hook());
+    match Arc::try_unwrap(previous) {
+        Ok(previous) => panic::set_hook(previous),
+        Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+    }
+
+    result
+}
+
+fn describe_outcome(panic_message: &Option<String>) -> String {
+    match *panic_message {
+        Some(ref message) => format!("panicked with <{:?}>", message),
+        None => "did not panic".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    fn parse(input: &str) -> usize {
+        if input.is_empty() {
+            panic!("empty input");
+        }
+
+        input.len()
+    }
+
+    #[test]
+    fn should_not_panic_if_closure_panics() {
+        assert_that(&|| parse("")).panics();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: closure to panic\n\t but was: did not panic")]
+    fn should_panic_if_closure_does_not_panic() {
+        assert_that(&|| parse("1")).panics();
+    }
+
+    #[test]
+    fn should_not_panic_if_closure_does_not_panic_when_expected_not_to() {
+        assert_that(&|| parse("1")).does_not_panic();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: closure to not panic\
+                   \n\t but was: panicked with <\"empty input\">")]
+    fn should_panic_if_closure_panics_when_expected_not_to() {
+        assert_that(&|| parse("")).does_not_panic();
+    }
+
+    #[test]
+    fn should_not_panic_if_closure_panics_with_expected_message() {
+        assert_that(&|| parse("")).panics_with_message("empty input");
+        assert_that(&|| panic!("{} input", "empty")).panics_with_message("empty input");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: closure to panic with message <\"bad input\">\
+                   \n\t but was: panicked with <\"empty input\">")]
+    fn should_panic_if_closure_panics_with_different_message() {
+        assert_that(&|| parse("")).panics_with_message("bad input");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: closure to panic with message <\"empty input\">\
+                   \n\t but was: did not panic")]
+    fn should_panic_if_closure_does_not_panic_when_message_expected() {
+        assert_that(&|| parse("1")).panics_with_message("empty input");
+    }
+
+    #[test]
+    fn should_return_panic_message_for_string_assertions() {
+        assert_that(&|| parse("")).panic_message().starts_with("empty").ends_with("input");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"bad\">\
+                   \n\t but was: <\"empty input\">")]
+    fn should_panic_if_panic_message_does_not_match() {
+        assert_that(&|| parse("")).panic_message().contains("bad");
+    }
+
+    #[test]
+    fn should_report_failing_assertions_in_closure_as_panics() {
+        assert_all(|s| {
+            s.that(&|| {
+                    assert_that(&1).is_equal_to(2);
+                })
+                .panic_message()
+                .contains("expected: <2>");
+        });
+    }
+
+    #[test]
+    fn should_allow_panic_assertions_inside_asserted_closure() {
+        assert_that(&|| {
+                assert_that(&|| parse("")).panics();
+            })
+            .does_not_panic();
+    }
+}
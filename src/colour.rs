@@ -0,0 +1,202 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const TERM_RED: &str = "\x1B[31m";
+const TERM_BOLD: &str = "\x1B[1m";
+const TERM_RESET: &str = "\x1B[0m";
+
+// Disable colours by default during tests, otherwise trying to assert on the panic message
+// becomes significantly more annoying.
+#[cfg(not(test))]
+static COLOUR_MODE: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(test)]
+static COLOUR_MODE: AtomicUsize = AtomicUsize::new(2);
+
+/// Controls whether failure messages are coloured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColourMode {
+    /// Colour failure messages if stderr is a terminal. This can be overridden by setting the
+    /// `NO_COLOR` or `CLICOLOR_FORCE` environment variables.
+    Auto,
+    /// Always colour failure messages.
+    Always,
+    /// Never colour failure messages.
+    Never,
+}
+
+impl ColourMode {
+    fn from_usize(value: usize) -> ColourMode {
+        match value {
+            1 => ColourMode::Always,
+            2 => ColourMode::Never,
+            _ => ColourMode::Auto,
+        }
+    }
+
+    fn to_usize(self) -> usize {
+        match self {
+            ColourMode::Auto => 0,
+            ColourMode::Always => 1,
+            ColourMode::Never => 2,
+        }
+    }
+}
+
+/// Sets whether failure messages are coloured for the rest of the process.
+///
+/// ```rust,ignore
+/// spectral::set_colour_mode(ColourMode::Never);
+/// ```
+pub fn set_colour_mode(mode: ColourMode) {
+    COLOUR_MODE.store(mode.to_usize(), Ordering::SeqCst);
+}
+
+/// Returns the current colour mode.
+pub fn colour_mode() -> ColourMode {
+    ColourMode::from_usize(COLOUR_MODE.load(Ordering::SeqCst))
+}
+
+/// Styles the different parts of a failure message.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Theme {
+    coloured: bool,
+}
+
+impl Theme {
+    /// The theme to use for panic messages, based upon the current colour mode.
+    pub fn current() -> Theme {
+        let coloured = should_colour(colour_mode(),
+                                     env::var_os("NO_COLOR"),
+                                     env::var_os("CLICOLOR_FORCE"),
+                                     io::stderr().is_terminal());
+
+        Theme { coloured }
+    }
+
+    /// A theme which never adds colours.
+    pub fn plain() -> Theme {
+        Theme { coloured: false }
+    }
+
+    /// Styles the expected and actual values.
+    pub fn failure(&self, text: &str) -> String {
+        self.style(TERM_RED, text)
+    }
+
+    /// Styles descriptions, subject names and locations.
+    pub fn label(&self, text: &str) -> String {
+        self.style(TERM_BOLD, text)
+    }
+
+    /// Styles the changed part of a line in a diff.
+    pub fn highlight(&self, text: &str) -> String {
+        if !self.coloured {
+            return text.to_string();
+        }
+
+        format!("{}{}{}{}", TERM_RED, TERM_BOLD, text, TERM_RESET)
+    }
+
+    fn style(&self, code: &str, text: &str) -> String {
+        if !self.coloured {
+            return text.to_string();
+        }
+
+        format!("{}{}{}", code, text, TERM_RESET)
+    }
+}
+
+fn should_colour(mode: ColourMode,
+                 no_color: Option<OsString>,
+                 clicolor_force: Option<OsString>,
+                 is_terminal: bool)
+                 -> bool {
+    match mode {
+        ColourMode::Always => return true,
+        ColourMode::Never => return false,
+        ColourMode::Auto => (),
+    }
+
+    if no_color.is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+
+    if clicolor_force.is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+
+    is_terminal
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::{should_colour, ColourMode, Theme};
+
+    use std::ffi::OsString;
+
+    #[test]
+    fn should_colour_if_stderr_is_a_terminal() {
+        assert_that(&should_colour(ColourMode::Auto, None, None, true)).is_true();
+        assert_that(&should_colour(ColourMode::Auto, None, None, false)).is_false();
+    }
+
+    #[test]
+    fn should_not_colour_if_no_color_is_set() {
+        let no_color = Some(OsString::from("1"));
+        assert_that(&should_colour(ColourMode::Auto, no_color, None, true)).is_false();
+    }
+
+    #[test]
+    fn should_ignore_empty_no_color() {
+        let no_color = Some(OsString::from(""));
+        assert_that(&should_colour(ColourMode::Auto, no_color, None, true)).is_true();
+    }
+
+    #[test]
+    fn should_colour_if_clicolor_force_is_set() {
+        let force = Some(OsString::from("1"));
+        assert_that(&should_colour(ColourMode::Auto, None, force, false)).is_true();
+
+        let disabled = Some(OsString::from("0"));
+        assert_that(&should_colour(ColourMode::Auto, None, disabled, false)).is_false();
+    }
+
+    #[test]
+    fn should_prefer_no_color_to_clicolor_force() {
+        let no_color = Some(OsString::from("1"));
+        let force = Some(OsString::from("1"));
+        assert_that(&should_colour(ColourMode::Auto, no_color, force, true)).is_false();
+    }
+
+    #[test]
+    fn should_respect_explicit_colour_mode() {
+        let no_color = Some(OsString::from("1"));
+        assert_that(&should_colour(ColourMode::Always, no_color, None, false)).is_true();
+
+        let force = Some(OsString::from("1"));
+        assert_that(&should_colour(ColourMode::Never, None, force, true)).is_false();
+    }
+
+    #[test]
+    fn should_not_add_colours_to_plain_theme() {
+        let theme = Theme::plain();
+
+        assert_that(&theme.failure("expected")).is_equal_to("expected".to_string());
+        assert_that(&theme.label("location")).is_equal_to("location".to_string());
+        assert_that(&theme.highlight("diff")).is_equal_to("diff".to_string());
+    }
+
+    #[test]
+    fn should_add_colours_to_coloured_theme() {
+        let theme = Theme { coloured: true };
+
+        assert_that(&theme.failure("expected")).is_equal_to("\x1B[31mexpected\x1B[0m".to_string());
+        assert_that(&theme.label("location")).is_equal_to("\x1B[1mlocation\x1B[0m".to_string());
+        assert_that(&theme.highlight("diff")).is_equal_to("\x1B[31m\x1B[1mdiff\x1B[0m".to_string());
+    }
+}
@@ -0,0 +1,269 @@
+use super::colour::Theme;
+
+/// Number of unchanged lines to show either side of a change.
+const CONTEXT_LINES: usize = 3;
+
+/// Above this many line comparisons, fall back to replacing every line rather than finding the
+/// smallest set of changes.
+const MAX_COMPARISONS: usize = 4_000_000;
+
+#[derive(Debug, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Builds a line-by-line diff of the expected and actual values, with removed lines marked with
+/// `-` and added lines marked with `+`. Unchanged lines far away from a change are elided.
+pub fn build_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    format_diff(&diff_lines(&expected, &actual), None)
+}
+
+/// Builds a diff of the expected and actual lines in the same way as `build_diff`, but with each
+/// line prefixed by its line number. Removed lines are numbered by their position in the
+/// expected lines, and other lines by their position in the actual lines. Carriage returns are
+/// shown as `\r`, so that differences in line endings are visible.
+pub fn build_line_diff(expected: &[&str], actual: &[&str]) -> String {
+    let width = expected.len().max(actual.len()).to_string().len();
+
+    format_diff(&diff_lines(expected, actual), Some(width))
+}
+
+fn format_diff(lines: &[Line], number_width: Option<usize>) -> String {
+    let mut output: Vec<String> = vec![];
+    let mut elided = false;
+    let (mut expected_number, mut actual_number) = (0, 0);
+
+    for (index, line) in lines.iter().enumerate() {
+        let (marker, value, number) = match *line {
+            Line::Same(value) => {
+                expected_number += 1;
+                actual_number += 1;
+                ("  ", value, actual_number)
+            }
+            Line::Removed(value) => {
+                expected_number += 1;
+                ("- ", value, expected_number)
+            }
+            Line::Added(value) => {
+                actual_number += 1;
+                ("+ ", value, actual_number)
+            }
+        };
+
+        if let Line::Same(_) = *line {
+            if !is_near_change(lines, index) {
+                if !elided {
+                    output.push("  ...".to_string());
+                    elided = true;
+                }
+                continue;
+            }
+        }
+
+        let formatted = match number_width {
+            Some(width) => {
+                format!("{}{:>width$} | {}",
+                        marker,
+                        number,
+                        value.replace('\r', "\\r"),
+                        width = width)
+            }
+            None => format!("{}{}", marker, value),
+        };
+
+        elided = false;
+        output.push(formatted);
+    }
+
+    output.join("\n")
+}
+
+/// Colours a diff produced by `build_diff`, highlighting the characters which changed between
+/// each removed line and the added line which replaced it.
+pub fn highlight_diff(diff: &str, theme: &Theme) -> String {
+    let lines: Vec<&str> = diff.lines().collect();
+    let mut output: Vec<String> = vec![];
+
+    let mut index = 0;
+    while index < lines.len() {
+        if !lines[index].starts_with('-') {
+            output.push(lines[index].to_string());
+            index += 1;
+            continue;
+        }
+
+        let removed: Vec<&str> = lines[index..].iter()
+            .take_while(|line| line.starts_with('-'))
+            .cloned()
+            .collect();
+        let added: Vec<&str> = lines[index + removed.len()..]
+            .iter()
+            .take_while(|line| line.starts_with('+'))
+            .cloned()
+            .collect();
+
+        for (position, line) in removed.iter().enumerate() {
+            output.push(highlight_line(line, added.get(position).cloned(), theme));
+        }
+        for (position, line) in added.iter().enumerate() {
+            output.push(highlight_line(line, removed.get(position).cloned(), theme));
+        }
+
+        index += removed.len() + added.len();
+    }
+
+    output.join("\n")
+}
+
+fn highlight_line(line: &str, other: Option<&str>, theme: &Theme) -> String {
+    let (marker, value) = line.split_at(2);
+
+    let other = match other {
+        Some(other) => &other[2..],
+        None => return theme.failure(line),
+    };
+
+    let chars: Vec<char> = value.chars().collect();
+    let other_chars: Vec<char> = other.chars().collect();
+
+    let prefix = chars.iter().zip(other_chars.iter()).take_while(|&(a, b)| a == b).count();
+    let suffix = chars[prefix..]
+        .iter()
+        .rev()
+        .zip(other_chars[prefix..].iter().rev())
+        .take_while(|&(a, b)| a == b)
+        .count();
+
+    let start: String = chars[..prefix].iter().collect();
+    let changed: String = chars[prefix..chars.len() - suffix].iter().collect();
+    let end: String = chars[chars.len() - suffix..].iter().collect();
+
+    format!("{}{}{}",
+            theme.failure(&format!("{}{}", marker, start)),
+            theme.highlight(&changed),
+            theme.failure(&end))
+}
+
+fn is_near_change(lines: &[Line], index: usize) -> bool {
+    let start = index.saturating_sub(CONTEXT_LINES);
+    let end = (index + CONTEXT_LINES + 1).min(lines.len());
+
+    lines[start..end].iter().any(|line| !matches!(*line, Line::Same(_)))
+}
+
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Line<'a>> {
+    if expected.len().saturating_mul(actual.len()) > MAX_COMPARISONS {
+        let mut lines: Vec<Line> = expected.iter().map(|line| Line::Removed(line)).collect();
+        lines.extend(actual.iter().map(|line| Line::Added(line)));
+        return lines;
+    }
+
+    // Lengths of the longest common subsequence of the remaining lines, starting from each
+    // position in the expected and actual lines.
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected.len() && j < actual.len() {
+        if expected[i] == actual[j] {
+            lines.push(Line::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            lines.push(Line::Removed(expected[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(actual[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(expected[i..].iter().map(|line| Line::Removed(line)));
+    lines.extend(actual[j..].iter().map(|line| Line::Added(line)));
+
+    group_changes(lines)
+}
+
+/// Moves each run of removed lines ahead of the added lines it is interleaved with, so that
+/// replaced lines can be paired up when highlighting.
+fn group_changes(lines: Vec<Line>) -> Vec<Line> {
+    let mut grouped = vec![];
+    let mut removed = vec![];
+    let mut added = vec![];
+
+    for line in lines {
+        match line {
+            Line::Same(_) => {
+                grouped.append(&mut removed);
+                grouped.append(&mut added);
+                grouped.push(line);
+            }
+            Line::Removed(_) => removed.push(line),
+            Line::Added(_) => added.push(line),
+        }
+    }
+
+    grouped.append(&mut removed);
+    grouped.append(&mut added);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::{build_diff, build_line_diff, highlight_diff};
+    use super::super::colour::Theme;
+
+    #[test]
+    fn should_mark_removed_and_added_lines() {
+        let diff = build_diff("a\nb\nc", "a\nd\nc");
+        assert_that(&diff).is_equal_to("  a\n- b\n+ d\n  c".to_string());
+    }
+
+    #[test]
+    fn should_group_removed_lines_before_added_lines() {
+        let diff = build_diff("a\nb\nc\nd", "a\nx\ny\nd");
+        assert_that(&diff).is_equal_to("  a\n- b\n- c\n+ x\n+ y\n  d".to_string());
+    }
+
+    #[test]
+    fn should_mark_trailing_lines() {
+        let diff = build_diff("a", "a\nb");
+        assert_that(&diff).is_equal_to("  a\n+ b".to_string());
+    }
+
+    #[test]
+    fn should_elide_unchanged_lines_far_from_changes() {
+        let diff = build_diff("1\n2\n3\n4\n5\n6\n7\n8\n9", "1\n2\n3\n4\n5\n6\n7\n8\nx");
+        assert_that(&diff).is_equal_to("  ...\n  6\n  7\n  8\n- 9\n+ x".to_string());
+    }
+
+    #[test]
+    fn should_number_lines_in_line_diff() {
+        let diff = build_line_diff(&["a", "b", "c"], &["a", "c\r", "d"]);
+        assert_that(&diff)
+            .is_equal_to("  1 | a\n- 2 | b\n- 3 | c\n+ 2 | c\\r\n+ 3 | d".to_string());
+    }
+
+    #[test]
+    fn should_not_change_diff_when_highlighting_without_colours() {
+        let diff = build_diff("a\nb\nc", "a\nd\nc");
+        assert_that(&highlight_diff(&diff, &Theme::plain())).is_equal_to(&diff);
+    }
+}
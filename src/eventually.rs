@@ -0,0 +1,178 @@
+use super::{assert_that, check_that, AssertionFailure, Spec};
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Repeatedly evaluates a supplier until assertions against its value pass, or a timeout
+/// expires.
+///
+/// This is created by either the `eventually` function or the `assert_that_eventually`
+/// function.
+#[derive(Debug)]
+pub struct Eventually<'r, F> {
+    supplier: F,
+    subject_name: Option<&'r str>,
+    timeout: Duration,
+    interval: Duration,
+}
+
+/// Creates an `Eventually` which will re-evaluate the supplier until the assertions passed to
+/// `satisfies` pass, failing if they have not passed within the timeout.
+///
+/// ```rust,ignore
+/// eventually(Duration::from_secs(1), || counter.load(Ordering::SeqCst))
+///     .satisfies(|spec| {
+///         spec.is_equal_to(5);
+///     });
+/// ```
+pub fn eventually<'r, T, F>(timeout: Duration, supplier: F) -> Eventually<'r, F>
+    where F: FnMut() -> T
+{
+    assert_that_eventually(supplier).within(timeout)
+}
+
+/// Creates an `Eventually` for the supplier, with a default timeout of one second.
+///
+/// ```rust,ignore
+/// assert_that_eventually(|| queue.len())
+///     .within(Duration::from_millis(500))
+///     .satisfies(|spec| {
+///         spec.is_greater_than(0);
+///     });
+/// ```
+pub fn assert_that_eventually<'r, T, F>(supplier: F) -> Eventually<'r, F>
+    where F: FnMut() -> T
+{
+    Eventually {
+        supplier,
+        subject_name: None,
+        timeout: DEFAULT_TIMEOUT,
+        interval: DEFAULT_INTERVAL,
+    }
+}
+
+impl<'r, F> Eventually<'r, F> {
+    /// Sets how long to keep re-evaluating the supplier before failing.
+    pub fn within(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how long to wait between evaluations of the supplier. Defaults to 10 milliseconds.
+    pub fn polling_every(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Names the subject, which is then used in the failure message.
+    pub fn named(mut self, subject_name: &'r str) -> Self {
+        self.subject_name = Some(subject_name);
+        self
+    }
+}
+
+impl<'r, T, F> Eventually<'r, F>
+    where F: FnMut() -> T,
+          T: Debug
+{
+    /// Evaluates the supplier and runs the assertions against its value until they pass,
+    /// returning the value which passed.
+    ///
+    /// If the assertions have still not passed when the timeout expires, this fails with the
+    /// last value observed, along with the number of attempts made and the time taken.
+    #[track_caller]
+    pub fn satisfies<A>(mut self, mut assertions: A) -> T
+        where A: FnMut(&mut Spec<T>)
+    {
+        let started = Instant::now();
+        let mut attempts = 0;
+
+        loop {
+            let value = (self.supplier)();
+            attempts += 1;
+
+            let error = match check_that(&value, |spec| assertions(spec)) {
+                Ok(()) => return value,
+                Err(error) => error,
+            };
+
+            let elapsed = started.elapsed();
+            if elapsed >= self.timeout {
+                let expected = error.expected.or(error.message).unwrap_or_default();
+
+                let mut spec = assert_that(&value);
+                spec.subject_name = self.subject_name.map(Cow::Borrowed);
+
+                AssertionFailure::from_spec(&spec)
+                    .with_expected(format!("{} within {:?}", expected, self.timeout))
+                    .with_actual(format!("<{:?}> after {} attempt(s) in {:?}",
+                                         value,
+                                         attempts,
+                                         elapsed))
+                    .fail();
+
+                return value;
+            }
+
+            thread::sleep(self.interval.min(self.timeout - elapsed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn should_not_panic_if_assertions_eventually_pass() {
+        let counter = AtomicUsize::new(0);
+
+        let value = eventually(Duration::from_secs(1), || counter.fetch_add(1, Ordering::SeqCst))
+            .satisfies(|spec| {
+                spec.is_equal_to(3);
+            });
+
+        assert_that(&value).is_equal_to(3);
+    }
+
+    #[test]
+    fn should_only_evaluate_supplier_once_if_assertions_pass_immediately() {
+        let counter = AtomicUsize::new(0);
+
+        assert_that_eventually(|| counter.fetch_add(1, Ordering::SeqCst)).satisfies(|spec| {
+            spec.is_equal_to(0);
+        });
+
+        assert_that(&counter.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <5> within 20ms\n\t but was: <2> after ")]
+    fn should_panic_with_last_value_if_assertions_do_not_pass_in_time() {
+        eventually(Duration::from_millis(20), || 2).satisfies(|spec| {
+            spec.is_equal_to(5);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [queue length]\
+                   \n\texpected: value greater than <0> within 5ms\n\t but was: <0> after ")]
+    fn should_use_polling_interval_and_subject_name() {
+        assert_that_eventually(|| 0)
+            .within(Duration::from_millis(5))
+            .polling_every(Duration::from_millis(50))
+            .named("queue length")
+            .satisfies(|spec| {
+                spec.is_greater_than(0);
+            });
+    }
+}
@@ -0,0 +1,138 @@
+use super::{AssertionFailure, DescriptiveSpec, Spec, Subject};
+
+use std::fmt::{Debug, Display};
+
+pub trait FormattingAssertions<'s, S> {
+    fn has_display_string<E: AsRef<str>>(&mut self, expected: E) -> &mut Self where S: Display;
+    fn has_debug_string<E: AsRef<str>>(&mut self, expected: E) -> &mut Self where S: Debug;
+    fn display(&self) -> Spec<'s, String> where S: Display;
+}
+
+impl<'s, S> FormattingAssertions<'s, S> for Spec<'s, S> {
+    /// Asserts that the subject is displayed as the expected string. The subject type must
+    /// implement `Display`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&error).has_display_string("file not found");
+    /// ```
+    #[track_caller]
+    fn has_display_string<E: AsRef<str>>(&mut self, expected: E) -> &mut Self
+        where S: Display
+    {
+        let expected = expected.as_ref();
+        let rendered = self.subject.to_string();
+
+        if !self.passes(rendered == expected) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("value displayed as <{:?}>", expected))
+                .with_negated_expected(format!("value not displayed as <{:?}>", expected))
+                .with_actual(format!("<{:?}>", rendered))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the `Debug` output of the subject is the expected string. The subject type
+    /// must implement `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Some(1)).has_debug_string("Some(1)");
+    /// ```
+    #[track_caller]
+    fn has_debug_string<E: AsRef<str>>(&mut self, expected: E) -> &mut Self
+        where S: Debug
+    {
+        let expected = expected.as_ref();
+        let rendered = format!("{:?}", self.subject);
+
+        if !self.passes(rendered == expected) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("value with debug string <{:?}>", expected))
+                .with_negated_expected(format!("value without debug string <{:?}>", expected))
+                .with_actual(format!("<{:?}>", rendered))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Creates a new `Spec` for the `Display` output of the subject, so that it can be checked
+    /// with any of the string assertions. The subject name, description and location of this
+    /// `Spec` are passed through.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&error).named("error").display().starts_with("file not found");
+    /// ```
+    fn display(&self) -> Spec<'s, String>
+        where S: Display
+    {
+        Spec {
+            subject: Subject::Owned(self.subject.to_string()),
+            subject_name: self.subject_name.clone(),
+            location: self.location.clone(),
+            description: self.description,
+            negated: self.negated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct OrderId(u32);
+
+    impl fmt::Display for OrderId {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "ORD-{:05}", self.0)
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_display_string_matches() {
+        assert_that(&OrderId(42)).has_display_string("ORD-00042");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value displayed as <\"ORD-42\">\
+                   \n\t but was: <\"ORD-00042\">")]
+    fn should_panic_if_display_string_does_not_match() {
+        assert_that(&OrderId(42)).has_display_string("ORD-42");
+    }
+
+    #[test]
+    fn should_not_panic_if_debug_string_matches() {
+        assert_that(&OrderId(42)).has_debug_string("OrderId(42)");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value with debug string <\"OrderId(7)\">\
+                   \n\t but was: <\"OrderId(42)\">")]
+    fn should_panic_if_debug_string_does_not_match() {
+        assert_that(&OrderId(42)).has_debug_string("OrderId(7)");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not displayed as <\"ORD-00042\">\
+                   \n\t but was: <\"ORD-00042\">")]
+    fn should_panic_if_display_string_matches_when_negated() {
+        assert_that(&OrderId(42)).not().has_display_string("ORD-00042");
+    }
+
+    #[test]
+    fn should_allow_string_assertions_on_display_output() {
+        assert_that(&OrderId(42)).display().starts_with("ORD-").ends_with("42");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\torder:\n\tfor subject [order id]\
+                   \n\texpected: string starting with <\"INV-\">")]
+    fn should_keep_subject_name_and_description_for_display_output() {
+        asserting("order").that(&OrderId(42)).named("order id").display().starts_with("INV-");
+    }
+}
@@ -1,4 +1,4 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec, Subject};
 
 use std::borrow::Borrow;
 use std::collections::HashMap;
@@ -6,14 +6,16 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 pub trait HashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
-    fn has_length(&mut self, expected: usize);
-    fn is_empty(&mut self);
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
     fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V>;
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E);
-    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self, expected_key: E, expected_value: F);
+    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) -> &mut Self;
+    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
+                                                  expected_key: E,
+                                                  expected_value: F) -> &mut Self;
     fn does_not_contain_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
                                                           expected_key: E,
-                                                          expected_value: F);
+                                                          expected_value: F) -> &mut Self;
 }
 
 impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
@@ -30,15 +32,19 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).has_length(2);
     /// ```
-    fn has_length(&mut self, expected: usize) {
-        let subject = self.subject;
+    #[track_caller]
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let subject = &*self.subject;
 
-        if subject.len() != expected {
+        if !self.passes(subject.len() == expected) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("hashmap to have length <{}>", expected))
+                .with_negated_expected(format!("hashmap to not have length <{}>", expected))
                 .with_actual(format!("<{}>", subject.len()))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject hashmap is empty. The subject type must be of `HashMap`.
@@ -47,15 +53,19 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     /// let test_map: HashMap<u8, u8> = HashMap::new();
     /// assert_that(&test_map).is_empty();
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = &*self.subject;
 
-        if !subject.is_empty() {
+        if !self.passes(subject.is_empty()) {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("an empty hashmap"))
+                .with_expected("an empty hashmap".to_string())
+                .with_negated_expected("a non-empty hashmap".to_string())
                 .with_actual(format!("a hashmap with length <{:?}>", subject.len()))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject hashmap contains the expected key. The subject type must be
@@ -69,16 +79,20 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).contains_key(&"hello");
     /// ```
+    #[track_caller]
     fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V> {
-        let subject = self.subject;
+        self.ensure_not_negated("contains_key");
+
+        let subject = self.projected_subject("contains_key");
         let borrowed_expected_key = expected_key.borrow();
 
         if let Some(value) = subject.get(borrowed_expected_key) {
             return Spec {
-                subject: value,
-                subject_name: self.subject_name,
+                subject: Subject::Borrowed(value),
+                subject_name: self.subject_name.clone(),
                 location: self.location.clone(),
                 description: self.description,
+                negated: false,
             };
         }
 
@@ -87,9 +101,7 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
         AssertionFailure::from_spec(self)
             .with_expected(format!("hashmap to contain key <{:?}>", borrowed_expected_key))
             .with_actual(format!("<{:?}>", subject_keys))
-            .fail();
-
-        unreachable!();
+            .fail_and_stop();
     }
 
     /// Asserts that the subject hashmap does not contain the provided key. The subject type must be
@@ -101,16 +113,28 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).does_not_contain_key(&"hey");
     /// ```
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_expected_key = expected_key.borrow();
 
-        if subject.get(borrowed_expected_key).is_some() {
+        if !self.passes(subject.get(borrowed_expected_key).is_none()) {
+            let actual = if self.negated {
+                let subject_keys: Vec<&K> = subject.keys().collect();
+                format!("<{:?}>", subject_keys)
+            } else {
+                "present in hashmap".to_string()
+            };
+
             AssertionFailure::from_spec(self)
                 .with_expected(format!("hashmap to not contain key <{:?}>", borrowed_expected_key))
-                .with_actual(format!("present in hashmap"))
+                .with_negated_expected(format!("hashmap to contain key <{:?}>",
+                                               borrowed_expected_key))
+                .with_actual(actual)
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject hashmap contains the expected key with the expected value.
@@ -122,8 +146,11 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).contains_entry(&"hello", &"hi");
     /// ```
-    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self, expected_key: E, expected_value: F) {
-        let subject = self.subject;
+    #[track_caller]
+    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
+                                                  expected_key: E,
+                                                  expected_value: F) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_expected_key = expected_key.borrow();
         let borrowed_expected_value = expected_value.borrow();
 
@@ -131,11 +158,25 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
                                        borrowed_expected_key,
                                        borrowed_expected_value);
 
-        if let Some(value) = subject.get(borrowed_expected_key) {
-            if value.eq(borrowed_expected_value) {
-                return;
-            }
+        let value = subject.get(borrowed_expected_key);
+        if self.passes(value == Some(borrowed_expected_value)) {
+            return self;
+        }
 
+        if self.negated {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_negated_expected(format!("hashmap to not contain key <{:?}> with value \
+                                                <{:?}>",
+                                               borrowed_expected_key,
+                                               borrowed_expected_value))
+                .with_actual("present in hashmap".to_string())
+                .fail();
+
+            return self;
+        }
+
+        if let Some(value) = value {
             AssertionFailure::from_spec(self)
                 .with_expected(expected_message)
                 .with_actual(format!("key <{:?}> with value <{:?}> instead",
@@ -143,7 +184,7 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
                                      value))
                 .fail();
 
-            unreachable!();
+            return self;
         }
 
         let subject_keys: Vec<&K> = subject.keys().collect();
@@ -153,6 +194,7 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
             .with_actual(format!("no matching key, keys are <{:?}>", subject_keys))
             .fail();
 
+        self
     }
 
     /// Asserts that the subject hashmap does not contains the provided key and value.
@@ -164,25 +206,11 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).does_not_contain_entry(&"hello", &"hey");
     /// ```
+    #[track_caller]
     fn does_not_contain_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
                                                           expected_key: E,
-                                                          expected_value: F) {
-        let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
-        let borrowed_expected_value = expected_value.borrow();
-
-        if let Some(value) = subject.get(borrowed_expected_key) {
-            if !value.eq(borrowed_expected_value) {
-                return;
-            }
-
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("hashmap to not contain key <{:?}> with value <{:?}>",
-                                       borrowed_expected_key,
-                                       borrowed_expected_value))
-                .with_actual(format!("present in hashmap"))
-                .fail();
-        }
+                                                          expected_value: F) -> &mut Self {
+        self.toggle_negation().contains_entry(expected_key, expected_value).toggle_negation()
     }
 }
 
@@ -359,4 +387,22 @@ mod tests {
 
         assert_that(&test_map).does_not_contain_entry(&"hello", &"hi");
     }
+
+    #[test]
+    fn should_not_panic_if_hashmap_does_not_contain_entry_when_negated() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).not().contains_entry("hello", "hey");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap to contain key <\"hey\">\
+                   \n\t but was: <[\"hello\"]>")]
+    fn should_panic_if_hashmap_does_not_contain_key_when_negated() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).not().does_not_contain_key("hey");
+    }
 }
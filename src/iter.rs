@@ -1,4 +1,4 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec};
 
 use std::borrow::Borrow;
 use std::cmp::PartialEq;
@@ -9,11 +9,11 @@ macro_rules! generate_iter_spec_trait {
         pub trait $trait_name<'s, T: 's>
             where T: Debug + PartialEq
             {
-                fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E);
-                fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+                fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
+                fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
                     where E: IntoIterator<Item = &'s T> + Clone;
-                fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E);
-                fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+                fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
+                fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
                     where E: Iterator<Item = &'s T> + Clone;
             }
     }
@@ -25,15 +25,18 @@ generate_iter_spec_trait!(ContainingIteratorAssertions);
 pub trait MappingIterAssertions<'s, T: 's>
     where T: Debug
 {
-    fn matching_contains<F>(&mut self, matcher: F) where F: Fn(&'s T) -> bool;
-    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M)
+    fn matching_contains<F>(&mut self, matcher: F) -> &mut Self where F: Fn(&T) -> bool;
+    fn mapped_contains<F, M: 's>(&mut self,
+                                 mapping_function: F,
+                                 expected_value: &M)
+                                 -> &mut Self
         where M: Debug + PartialEq,
-              F: Fn(&'s T) -> M;
+              F: Fn(&T) -> M;
 }
 
 impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     where T: Debug + PartialEq,
-          &'s I: IntoIterator<Item = &'s T>
+          for<'a> &'a I: IntoIterator<Item = &'a T>
 {
     /// Asserts that the subject contains the provided value. The subject must implement
     /// `IntoIterator`, and the contained type must implement `PartialEq` and `Debug`.
@@ -42,9 +45,12 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).contains(&2);
     /// ```
-    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.into_iter();
+    #[track_caller]
+    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = (&*self.subject).into_iter();
         check_iterator_contains(self, subject_iter, expected_value, true);
+
+        self
     }
 
     /// Asserts that the subject contains all of the provided values. The subject must implement
@@ -54,12 +60,15 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).contains_all_of(&vec![2, 3]);
     /// ```
-    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+    #[track_caller]
+    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
         where E: IntoIterator<Item = &'s T> + Clone
     {
-        let subject_iter = self.subject.into_iter();
+        let subject_iter = (&*self.subject).into_iter();
         let expected_iter = expected_values_iter.clone().into_iter();
         check_iterator_contains_all_of(self, subject_iter, expected_iter);
+
+        self
     }
 
     /// Asserts that the subject does not contain the provided value. The subject must implement
@@ -69,9 +78,12 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).does_not_contain(&4);
     /// ```
-    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.into_iter();
+    #[track_caller]
+    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = (&*self.subject).into_iter();
         check_iterator_contains(self, subject_iter, expected_value, false);
+
+        self
     }
 
     /// Asserts that the subject is equal to provided iterator. The subject must implement
@@ -83,10 +95,14 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).equals_iterator(&expected_vec.iter());
     /// ```
-    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+    #[track_caller]
+    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
         where E: Iterator<Item = &'s T> + Clone
     {
-        compare_iterators(self, self.subject.into_iter(), expected_iter.clone());
+        let subject_iter = (&*self.subject).into_iter();
+        compare_iterators(self, subject_iter, expected_iter.clone());
+
+        self
     }
 }
 
@@ -101,9 +117,12 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).contains(&2);
     /// ```
-    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) {
+    #[track_caller]
+    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
         let subject_iter = self.subject.clone();
         check_iterator_contains(self, subject_iter, expected_value, true);
+
+        self
     }
 
     /// Asserts that the subject contains all of the provided values. The subject must implement
@@ -113,12 +132,15 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).contains_all_of(&vec![2, 3]);
     /// ```
-    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+    #[track_caller]
+    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
         where E: IntoIterator<Item = &'s T> + Clone
     {
         let subject_iter = self.subject.clone();
         let expected_iter = expected_values_iter.clone().into_iter();
         check_iterator_contains_all_of(self, subject_iter, expected_iter);
+
+        self
     }
 
     /// Asserts that the iterable subject does not contain the provided value. The subject must
@@ -128,9 +150,12 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).does_not_contain(&4);
     /// ```
-    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) {
+    #[track_caller]
+    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
         let subject_iter = self.subject.clone();
         check_iterator_contains(self, subject_iter, expected_value, false);
+
+        self
     }
 
     /// Asserts that the iterable subject is equal to provided iterator. The subject must implement
@@ -142,16 +167,19 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).equals_iterator(&expected_vec.iter());
     /// ```
-    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+    #[track_caller]
+    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
         where E: Iterator<Item = &'s T> + Clone
     {
         compare_iterators(self, self.subject.clone(), expected_iter.clone());
+
+        self
     }
 }
 
 impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     where T: Debug,
-          &'s I: IntoIterator<Item = &'s T>
+          for<'a> &'a I: IntoIterator<Item = &'a T>
 {
     /// Maps the values of the subject before asserting that the mapped subject contains the
     /// provided value. The subject must implement IntoIterator, and the type of the mapped
@@ -170,18 +198,22 @@ impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     ///
     /// assert_that(&vec![Simple { val: 1 }, Simple { val: 2 } ]).mapped_contains(|x| &x.val, &2);
     /// ```
-    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M)
+    #[track_caller]
+    fn mapped_contains<F, M: 's>(&mut self,
+                                 mapping_function: F,
+                                 expected_value: &M)
+                                 -> &mut Self
         where M: Debug + PartialEq,
-              F: Fn(&'s T) -> M
+              F: Fn(&T) -> M
     {
-        let subject = self.subject;
+        let subject = &*self.subject;
 
         let mapped_vec: Vec<M> = subject.into_iter().map(mapping_function).collect();
-        if mapped_vec.contains(expected_value) {
-            return;
+        if !self.passes(mapped_vec.contains(expected_value)) {
+            panic_unmatched(self, expected_value, mapped_vec, true);
         }
 
-        panic_unmatched(self, expected_value, mapped_vec, true);
+        self
     }
 
     /// Asserts that the subject contains a matching item by using the provided function.
@@ -200,24 +232,29 @@ impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     ///     }
     /// });
     /// ```
-    fn matching_contains<F>(&mut self, matcher: F)
-        where F: Fn(&'s T) -> bool
+    #[track_caller]
+    fn matching_contains<F>(&mut self, matcher: F) -> &mut Self
+        where F: Fn(&T) -> bool
     {
-        let mut actual = Vec::new();
-        for x in self.subject {
-            if matcher(x) {
-                return;
-            } else {
-                actual.push(x);
-            }
+        let actual: Vec<&T> = (&*self.subject).into_iter().collect();
+        if self.passes(actual.iter().any(|x| matcher(x))) {
+            return self;
         }
-        AssertionFailure::from_spec(self)
-            .fail_with_message(format!("expectation failed for iterator with values <{:?}>",
-                                       actual));
+
+        let message = if self.negated {
+            format!("expectation unexpectedly met for iterator with values <{:?}>", actual)
+        } else {
+            format!("expectation failed for iterator with values <{:?}>", actual)
+        };
+
+        AssertionFailure::from_spec(self).fail_with_message(message);
+
+        self
     }
 }
 
-fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &mut Spec<T>,
+#[track_caller]
+fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &Spec<T>,
                                                           actual_iter: I,
                                                           expected_value: E,
                                                           should_contain: bool)
@@ -237,19 +274,20 @@ fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &mut Spec<T>,
         actual.push(x);
     }
 
-    if contains_value != should_contain {
+    if !spec.passes(contains_value == should_contain) {
         panic_unmatched(spec, borrowed_expected_value, actual, should_contain);
     }
 }
 
-fn check_iterator_contains_all_of<T, V, I, E>(spec: &mut Spec<T>,
-                                              actual_iter: I,
-                                              expected_values_iter: E)
-    where V: PartialEq + Debug,
-          I: Iterator<Item = V>,
-          E: Iterator<Item = V>
+#[track_caller]
+fn check_iterator_contains_all_of<'a, 'e, T, V, I, E>(spec: &Spec<T>,
+                                                      actual_iter: I,
+                                                      expected_values_iter: E)
+    where V: 'a + 'e + PartialEq + Debug,
+          I: Iterator<Item = &'a V>,
+          E: Iterator<Item = &'e V>
 {
-    let actual_values: Vec<V> = actual_iter.collect();
+    let actual_values: Vec<&V> = actual_iter.collect();
 
     let mut matched_indexes = vec![];
     let mut matched_indexes_holder = vec![];
@@ -273,23 +311,26 @@ fn check_iterator_contains_all_of<T, V, I, E>(spec: &mut Spec<T>,
         unmatched_values.push(expected);
     }
 
-    if !unmatched_values.is_empty() {
-        let mut expected_values: Vec<V> = vec![];
+    if !spec.passes(unmatched_values.is_empty()) {
+        let mut expected_values: Vec<&V> = vec![];
 
         expected_values.append(&mut matched_values);
         expected_values.append(&mut unmatched_values);
 
         AssertionFailure::from_spec(spec)
             .with_expected(format!("iterator to contain items <{:?}>", expected_values))
+            .with_negated_expected(format!("iterator to not contain all of items <{:?}>",
+                                           expected_values))
             .with_actual(format!("<{:?}>", actual_values))
             .fail();
     }
 }
 
-fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_iter: E)
-    where V: PartialEq + Debug,
-          I: Iterator<Item = V>,
-          E: Iterator<Item = V>
+#[track_caller]
+fn compare_iterators<'a, 'e, T, V, I, E>(spec: &Spec<T>, actual_iter: I, expected_iter: E)
+    where V: 'a + 'e + PartialEq + Debug,
+          I: Iterator<Item = &'a V>,
+          E: Iterator<Item = &'e V>
 {
     let mut actual_iter = actual_iter;
     let mut expected_iter = expected_iter;
@@ -297,62 +338,72 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
     let mut read_subject = vec![];
     let mut read_expected = vec![];
 
-    loop {
+    let mismatch = loop {
         match (actual_iter.next(), expected_iter.next()) {
             (Some(actual), Some(expected)) => {
-                if !&actual.eq(&expected) {
-                    AssertionFailure::from_spec(spec)
-                        .with_expected(format!("Iterator item of <{:?}> (read <{:?}>)",
-                                               expected,
-                                               read_expected))
-                        .with_actual(format!("Iterator item of <{:?}> (read <{:?}>)",
-                                             actual,
-                                             read_subject))
-                        .fail();
-
-                    unreachable!();
+                if !&actual.eq(expected) {
+                    break Some((format!("Iterator item of <{:?}> (read <{:?}>)",
+                                        expected,
+                                        read_expected),
+                                format!("Iterator item of <{:?}> (read <{:?}>)",
+                                        actual,
+                                        read_subject)));
                 }
 
                 read_subject.push(actual);
                 read_expected.push(expected);
             }
             (Some(actual), None) => {
-                AssertionFailure::from_spec(spec)
-                    .with_expected(format!("Completed iterator (read <{:?}>)", read_expected))
-                    .with_actual(format!("Iterator item of <{:?}> (read <{:?}>",
-                                         actual,
-                                         read_subject))
-                    .fail();
-
-                unreachable!();
+                break Some((format!("Completed iterator (read <{:?}>)", read_expected),
+                            format!("Iterator item of <{:?}> (read <{:?}>",
+                                    actual,
+                                    read_subject)));
             }
             (None, Some(expected)) => {
-                AssertionFailure::from_spec(spec)
-                    .with_expected(format!("Iterator item of <{:?}> (read <{:?}>",
-                                           expected,
-                                           read_expected))
-                    .with_actual(format!("Completed iterator (read <{:?}>", read_subject))
-                    .fail();
-
-                unreachable!();
+                break Some((format!("Iterator item of <{:?}> (read <{:?}>",
+                                    expected,
+                                    read_expected),
+                            format!("Completed iterator (read <{:?}>", read_subject)));
             }
             (None, None) => {
-                break;
+                break None;
             }
         }
+    };
+
+    if spec.passes(mismatch.is_none()) {
+        return;
+    }
+
+    match mismatch {
+        Some((expected, actual)) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(expected)
+                .with_actual(actual)
+                .fail();
+        }
+        None => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("Iterator of <{:?}>", read_expected))
+                .with_negated_expected(format!("Iterator to not equal <{:?}>", read_expected))
+                .with_actual("equal".to_string())
+                .fail();
+        }
     }
 }
 
-fn panic_unmatched<T, E: Debug, A: Debug>(spec: &mut Spec<T>,
+#[track_caller]
+fn panic_unmatched<T, E: Debug, A: Debug>(spec: &Spec<T>,
                                           expected: E,
                                           actual: A,
                                           should_contain: bool) {
-    let condition = {
-        if should_contain { " " } else { " not " }
+    let (condition, negated_condition) = {
+        if should_contain { (" ", " not ") } else { (" not ", " ") }
     };
 
     AssertionFailure::from_spec(spec)
         .with_expected(format!("iterator to{}contain <{:?}>", condition, expected))
+        .with_negated_expected(format!("iterator to{}contain <{:?}>", negated_condition, expected))
         .with_actual(format!("<{:?}>", actual))
         .fail();
 }
@@ -397,6 +448,32 @@ mod tests {
         assert_that(&test_vec).does_not_contain(&2);
     }
 
+    #[test]
+    fn should_not_panic_if_vec_contains_value_when_negated() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).not().does_not_contain(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to not contain <2>\n\t but was: <[1, 2, 3]>")]
+    fn should_panic_if_vec_contains_value_when_negated() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).not().contains(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_iterators_are_not_equal_when_negated() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).not().equals_iterator(&[1, 2].iter());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Iterator to not equal <[1, 2, 3]>\n\t but was: equal")]
+    fn should_panic_if_iterators_are_equal_when_negated() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).not().equals_iterator(&test_vec.iter());
+    }
+
     #[test]
     fn should_not_panic_if_iterable_contains_value() {
         let mut test_into_iter = LinkedList::new();
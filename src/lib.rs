@@ -139,42 +139,57 @@
 //! Now, this was just a simple example, and there's a number of features not demonstrated, but
 //! hopefully it's enough to start you off with writing assertions in your tests using Spectral.
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::cmp::PartialEq;
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::ops::Deref;
+use std::panic::{self, Location};
 
-use colours::{TERM_RED, TERM_BOLD, TERM_RESET};
+use colour::Theme;
+use matchers::Matcher;
+
+pub use colour::{colour_mode, set_colour_mode, ColourMode};
+pub use reporter::{reset_failure_reporter, set_failure_reporter, with_failure_reporter,
+                   FailureReporter};
 
 pub mod boolean;
+pub mod closure;
+pub mod colour;
+pub mod eventually;
+pub mod formatting;
 pub mod hashmap;
+pub mod matchers;
 pub mod numeric;
 pub mod option;
 pub mod path;
 pub mod prelude;
+pub mod reporter;
 pub mod result;
+pub mod snapshot;
+pub mod soft;
 pub mod string;
+pub mod time;
 pub mod vec;
 pub mod iter;
 
-// Disable colours during tests, otherwise trying to assert on the panic message becomes
-// significantly more annoying.
-#[cfg(not(test))]
-mod colours {
-    pub const TERM_RED: &'static str = "\x1B[31m";
-    pub const TERM_BOLD: &'static str = "\x1B[1m";
-    pub const TERM_RESET: &'static str = "\x1B[0m";
-}
-
-#[cfg(test)]
-mod colours {
-    pub const TERM_RED: &'static str = "";
-    pub const TERM_BOLD: &'static str = "";
-    pub const TERM_RESET: &'static str = "";
-}
+mod diff;
 
 #[cfg(feature = "num")]
 extern crate num;
 
+#[cfg(feature = "regex")]
+extern crate regex;
+
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+
+/// Values with a `Debug` representation longer than this are compared with a diff.
+const MAX_INLINE_VALUE_LENGTH: usize = 60;
+
+/// Values which pretty-print to more lines than this are only shown as a diff.
+const MAX_PRETTY_VALUE_LINES: usize = 40;
+
 #[macro_export]
 macro_rules! assert_that {
     (&$subject:tt) => {
@@ -214,9 +229,26 @@ macro_rules! asserting {
 }
 
 pub trait DescriptiveSpec<'r> {
-    fn subject_name(&self) -> Option<&'r str>;
+    fn subject_name(&self) -> Option<&str>;
     fn location(&self) -> Option<String>;
     fn description(&self) -> Option<&'r str>;
+
+    /// Returns true if the assertion has been negated with `not()`.
+    fn is_negated(&self) -> bool {
+        false
+    }
+
+    /// Returns true if an assertion whose condition evaluated to the provided value should pass,
+    /// taking into account whether the assertion has been negated.
+    ///
+    /// ```rust,ignore
+    /// if !spec.passes(subject.starts_with(expected)) {
+    ///     AssertionFailure::from_spec(spec)...
+    /// }
+    /// ```
+    fn passes(&self, condition: bool) -> bool {
+        condition != self.is_negated()
+    }
 }
 
 /// A failed assertion.
@@ -226,7 +258,25 @@ pub trait DescriptiveSpec<'r> {
 pub struct AssertionFailure<'r, T: 'r> {
     spec: &'r T,
     expected: Option<String>,
+    negated_expected: Option<String>,
     actual: Option<String>,
+    diff: Option<String>,
+    caller: &'static Location<'static>,
+}
+
+/// The details of a failed assertion.
+///
+/// This is returned by `check_that`, and can be inspected rather than having the assertion
+/// panic. Displaying it produces the same message that the assertion would have panicked with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssertionError {
+    pub description: Option<String>,
+    pub subject_name: Option<String>,
+    pub location: Option<String>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub message: Option<String>,
+    pub diff: Option<String>,
 }
 
 /// A description for an assertion.
@@ -242,12 +292,43 @@ pub struct SpecDescription<'r> {
 ///
 /// This is created by either the `assert_that` function, or by calling `that` on a
 /// `SpecDescription`.
+///
+/// The subject may be borrowed or owned, so it is stored as a `Subject` rather than a reference.
+/// Use `subject()` to read it, or `borrowed_subject()` to get a borrowed subject for the whole
+/// lifetime of the `Spec`. A `Spec` cannot be built with a struct literal, as negation is private
+/// to it; use `assert_that` or `asserting` instead.
 #[derive(Debug)]
 pub struct Spec<'s, S: 's> {
-    pub subject: &'s S,
-    pub subject_name: Option<&'s str>,
+    pub subject: Subject<'s, S>,
+    pub subject_name: Option<Cow<'s, str>>,
     pub location: Option<String>,
     pub description: Option<&'s str>,
+    negated: bool,
+}
+
+/// The subject of a `Spec`, which is either borrowed from the caller or owned by the `Spec`.
+///
+/// This dereferences to the subject itself, so assertions can treat both in the same way.
+pub enum Subject<'s, S: 's> {
+    Borrowed(&'s S),
+    Owned(S),
+}
+
+impl<'s, S> Deref for Subject<'s, S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        match *self {
+            Subject::Borrowed(subject) => subject,
+            Subject::Owned(ref subject) => subject,
+        }
+    }
+}
+
+impl<'s, S: Debug> Debug for Subject<'s, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
 }
 
 /// Wraps a subject in a `Spec` to provide assertions against it.
@@ -255,10 +336,63 @@ pub struct Spec<'s, S: 's> {
 /// The subject must be a reference.
 pub fn assert_that<'s, S>(subject: &'s S) -> Spec<'s, S> {
     Spec {
-        subject: subject,
+        subject: Subject::Borrowed(subject),
         subject_name: None,
         location: None,
         description: None,
+        negated: false,
+    }
+}
+
+/// Wraps an owned subject in a `Spec` to provide assertions against it. This is useful for
+/// asserting on values computed in place, such as the result of a function call.
+///
+/// The subject is moved into the `Spec`, and dropped along with it.
+///
+/// ```rust,ignore
+/// assert_that_owned(compute_total()).is_equal_to(5);
+/// ```
+pub fn assert_that_owned<'s, S: 's>(subject: S) -> Spec<'s, S> {
+    Spec {
+        subject: Subject::Owned(subject),
+        subject_name: None,
+        location: None,
+        description: None,
+        negated: false,
+    }
+}
+
+/// Runs the provided assertions against the subject, returning the first failure as an
+/// `AssertionError` rather than panicking.
+///
+/// The value returned by the closure is passed through if every assertion passed.
+///
+/// Failing assertions are collected rather than panicking, so the closure carries on after a
+/// failure. Assertions which cannot carry on (such as `is_some`) end the closure by unwinding,
+/// and any other panic from the closure is propagated. This means `check_that` relies on
+/// unwinding: when built with `panic = "abort"`, such a failure aborts the process rather than
+/// being returned.
+///
+/// ```rust,ignore
+/// let result = check_that(&1, |spec| {
+///     spec.is_equal_to(&2);
+/// });
+/// assert_that(&result).is_err();
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn check_that<'s, S, F, R>(subject: &'s S, assertions: F) -> Result<R, AssertionError>
+    where F: FnOnce(&mut Spec<'s, S>) -> R
+{
+    let mut spec = assert_that(subject);
+    let (result, failures) = soft::collect_failures(|| assertions(&mut spec));
+
+    if let Some(error) = failures.into_iter().next() {
+        return Err(error);
+    }
+
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) => panic::resume_unwind(payload),
     }
 }
 
@@ -280,18 +414,30 @@ impl<'r> SpecDescription<'r> {
 
     /// Creates a new assertion, passing through its description.
     pub fn that<S>(self, subject: &'r S) -> Spec<'r, S> {
+        self.that_subject(Subject::Borrowed(subject))
+    }
+
+    /// Creates a new assertion against an owned subject, passing through its description.
+    ///
+    /// As with `assert_that_owned`, the subject is moved into the `Spec`.
+    pub fn that_owned<S: 'r>(self, subject: S) -> Spec<'r, S> {
+        self.that_subject(Subject::Owned(subject))
+    }
+
+    fn that_subject<S>(self, subject: Subject<'r, S>) -> Spec<'r, S> {
         Spec {
             subject: subject,
             subject_name: None,
             location: self.location,
             description: Some(self.value),
+            negated: false,
         }
     }
 }
 
 impl<'r, T> DescriptiveSpec<'r> for Spec<'r, T> {
-    fn subject_name(&self) -> Option<&'r str> {
-        self.subject_name
+    fn subject_name(&self) -> Option<&str> {
+        self.subject_name.as_deref()
     }
 
     fn location(&self) -> Option<String> {
@@ -301,15 +447,27 @@ impl<'r, T> DescriptiveSpec<'r> for Spec<'r, T> {
     fn description(&self) -> Option<&'r str> {
         self.description
     }
+
+    fn is_negated(&self) -> bool {
+        self.negated
+    }
 }
 
 impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
     /// Construct a new AssertionFailure from a DescriptiveSpec.
+    ///
+    /// If the spec has no location, the location of the caller is used instead. Assertions
+    /// should be marked with `#[track_caller]`, so that this is the location of the assertion in
+    /// the test rather than inside the assertion itself.
+    #[track_caller]
     pub fn from_spec(spec: &'r T) -> AssertionFailure<'r, T> {
         AssertionFailure {
             spec: spec,
             expected: None,
+            negated_expected: None,
             actual: None,
+            diff: None,
+            caller: Location::caller(),
         }
     }
 
@@ -321,6 +479,13 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         assertion
     }
 
+    /// Builder method to add the expected value for the panic message if the assertion has been
+    /// negated with `not()`. If this is not provided, the expected value is prefixed with "not".
+    pub fn with_negated_expected(&mut self, negated_expected: String) -> &mut Self {
+        self.negated_expected = Some(negated_expected);
+        self
+    }
+
     /// Builder method to add the actual value for the panic message.
     pub fn with_actual(&mut self, actual: String) -> &mut Self {
         let mut assertion = self;
@@ -329,70 +494,165 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         assertion
     }
 
+    /// Builder method to add a line-by-line diff of the expected and actual values to the panic
+    /// message.
+    pub fn with_diff(&mut self, expected: &str, actual: &str) -> &mut Self {
+        self.diff = Some(diff::build_diff(expected, actual));
+        self
+    }
+
+    /// Builder method to add a line-numbered diff of the expected and actual lines to the panic
+    /// message.
+    pub fn with_line_diff(&mut self, expected: &[&str], actual: &[&str]) -> &mut Self {
+        self.diff = Some(diff::build_line_diff(expected, actual));
+        self
+    }
+
     /// Builds the failure message with a description (if present), the expected value,
     /// and the actual value and then calls `panic` with the created message.
+    ///
+    /// If the assertion is running inside an `assert_all` block or a `check_that` call, the
+    /// failure is collected instead and this will return normally.
+    #[track_caller]
     pub fn fail(&mut self) {
         if !self.expected.is_some() || !self.actual.is_some() {
             panic!("invalid assertion");
         }
 
-        let location = self.maybe_build_location();
-        let subject_name = self.maybe_build_subject_name();
-        let description = self.maybe_build_description();
+        let error = self.build_error(None);
+        raise(error);
+    }
 
-        panic!(format!("{}{}\n\t{}expected: {}\n\t but was: {}{}\n{}",
-                       description,
-                       subject_name,
-                       TERM_RED,
-                       self.expected.clone().unwrap(),
-                       self.actual.clone().unwrap(),
-                       TERM_RESET,
-                       location))
+    /// Fails the assertion in the same way as `fail`, but never returns.
+    ///
+    /// This should be used by assertions which are unable to carry on after failing, such as
+    /// those which return a new `Spec`. Inside an `assert_all` block the failure is still
+    /// collected, but the rest of the block will be skipped. Otherwise, if a `FailureReporter`
+    /// returns normally from reporting the failure, this panics with the failure message.
+    #[track_caller]
+    pub fn fail_and_stop(&mut self) -> ! {
+        self.fail();
+
+        if soft::is_collecting() {
+            soft::stop()
+        }
+
+        panic!("{}", self.build_error(None).build_message(&Theme::current()))
     }
 
     /// Calls `panic` with the provided message, prepending the assertion description
     /// if present.
+    #[track_caller]
     fn fail_with_message(&mut self, message: String) {
-        let location = self.maybe_build_location();
-        let subject_name = self.maybe_build_subject_name();
-        let description = self.maybe_build_description();
-
-        panic!(format!("{}{}\n\t{}{}{}\n{}",
-                       description,
-                       subject_name,
-                       TERM_RED,
-                       message,
-                       TERM_RESET,
-                       location))
-    }
-
-    fn maybe_build_location(&self) -> String {
-        match self.spec.location() {
-            Some(value) => format!("\n\t{}at location: {}{}\n", TERM_BOLD, value, TERM_RESET),
+        let error = self.build_error(Some(message));
+        raise(error);
+    }
+
+    fn build_error(&self, message: Option<String>) -> AssertionError {
+        let expected = if self.spec.is_negated() && message.is_none() {
+            match self.negated_expected {
+                Some(ref negated_expected) => Some(negated_expected.clone()),
+                None => self.expected.as_ref().map(|value| format!("not {}", value)),
+            }
+        } else {
+            self.expected.clone()
+        };
+
+        AssertionError {
+            description: self.spec.description().map(|value| value.to_string()),
+            subject_name: self.spec.subject_name().map(|value| value.to_string()),
+            location: self.spec.location().or_else(|| {
+                Some(format!("{}:{}", self.caller.file(), self.caller.line()))
+            }),
+            expected,
+            actual: self.actual.clone(),
+            message,
+            diff: self.diff.clone(),
+        }
+    }
+}
+
+impl AssertionError {
+    /// Builds the same message that a failing assertion would panic with.
+    fn build_message(&self, theme: &Theme) -> String {
+        let body = match self.message {
+            Some(ref message) => message.clone(),
+            None => {
+                format!("expected: {}\n\t but was: {}",
+                        self.expected.as_ref().map_or("", |value| value.as_str()),
+                        self.actual.as_ref().map_or("", |value| value.as_str()))
+            }
+        };
+
+        format!("{}{}\n\t{}{}\n{}",
+                self.maybe_build_description(theme),
+                self.maybe_build_subject_name(theme),
+                theme.failure(&body),
+                self.maybe_build_diff(theme),
+                self.maybe_build_location(theme))
+    }
+
+    fn maybe_build_diff(&self, theme: &Theme) -> String {
+        match self.diff {
+            Some(ref value) => {
+                format!("\n\n\t{}\n\t{}",
+                        theme.label("diff (- expected, + actual):"),
+                        diff::highlight_diff(value, theme).replace("\n", "\n\t"))
+            }
+            None => "".to_string(),
+        }
+    }
+
+    fn maybe_build_location(&self, theme: &Theme) -> String {
+        match self.location {
+            Some(ref value) => format!("\n\t{}\n", theme.label(&format!("at location: {}", value))),
             None => "".to_string(),
         }
     }
 
-    fn maybe_build_description(&self) -> String {
-        match self.spec.description() {
-            Some(value) => format!("\n\t{}{}:{}", TERM_BOLD, value, TERM_RESET),
+    fn maybe_build_description(&self, theme: &Theme) -> String {
+        match self.description {
+            Some(ref value) => format!("\n\t{}", theme.label(&format!("{}:", value))),
             None => "".to_string(),
         }
     }
 
-    fn maybe_build_subject_name(&self) -> String {
-        match self.spec.subject_name() {
-            Some(value) => format!("\n\t{}for subject [{}]{}", TERM_BOLD, value, TERM_RESET),
+    fn maybe_build_subject_name(&self, theme: &Theme) -> String {
+        match self.subject_name {
+            Some(ref value) => {
+                format!("\n\t{}", theme.label(&format!("for subject [{}]", value)))
+            }
             None => "".to_string(),
         }
     }
 }
 
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.build_message(&Theme::plain()))
+    }
+}
+
+impl Error for AssertionError {}
+
+/// Panics with the failure message, unless it has been collected by an `assert_all` block or a
+/// `check_that` call, or a `FailureReporter` has been set.
+#[track_caller]
+fn raise(error: AssertionError) {
+    if let Some(error) = soft::collect(error) {
+        match reporter::current_reporter() {
+            Some(reporter) => reporter.report(&error),
+            None => panic!("{}", error.build_message(&Theme::current())),
+        }
+    }
+}
+
 impl<'s, S> Spec<'s, S> {
     /// Provides the actual location of the assertion.
     ///
-    /// Usually you would not call this directly, but use the macro forms of `assert_that` and
-    /// `asserting`, which will call this on your behalf with the correct location.
+    /// Usually you would not call this directly, as the location of the assertion is captured
+    /// with `#[track_caller]`. The macro forms of `assert_that` and `asserting` call this on
+    /// your behalf with the location of the macro.
     pub fn at_location(self, location: String) -> Self {
         let mut spec = self;
         spec.location = Some(location);
@@ -405,10 +665,118 @@ impl<'s, S> Spec<'s, S> {
     /// This will be displayed if the assertion fails.
     pub fn named(self, subject_name: &'s str) -> Self {
         let mut spec = self;
-        spec.subject_name = Some(subject_name);
+        spec.subject_name = Some(Cow::Borrowed(subject_name));
+
+        spec
+    }
+
+    /// Returns the subject of the `Spec`, whether it is borrowed or owned.
+    pub fn subject(&self) -> &S {
+        &self.subject
+    }
+
+    /// Returns the name associated with the subject, if any.
+    pub fn subject_name(&self) -> Option<&str> {
+        self.subject_name.as_deref()
+    }
+
+    /// Returns the subject for the whole lifetime of the `Spec` if it is borrowed, or `None` if
+    /// the subject is owned by the `Spec`.
+    pub fn borrowed_subject(&self) -> Option<&'s S> {
+        match self.subject {
+            Subject::Borrowed(subject) => Some(subject),
+            Subject::Owned(_) => None,
+        }
+    }
+
+    /// Creates a new `Spec` which borrows the subject of this one, passing through its name,
+    /// location, description and negation.
+    ///
+    /// Assertions which return a new `Spec` for part of the subject (such as `is_some` or
+    /// `contains_key`) borrow it for the whole lifetime of the `Spec`, which an owned subject
+    /// cannot provide. Calling `by_ref` first lets them be used on owned subjects.
+    ///
+    /// ```rust,ignore
+    /// assert_that_owned(find_user()).by_ref().is_some().is_equal_to(&user);
+    /// ```
+    pub fn by_ref(&self) -> Spec<'_, S> {
+        Spec {
+            subject: Subject::Borrowed(&self.subject),
+            subject_name: self.subject_name.clone(),
+            location: self.location.clone(),
+            description: self.description,
+            negated: self.negated,
+        }
+    }
+
+    /// Creates a new `Spec` for a field of the subject, with the provided subject name. The
+    /// location, description and negation of this `Spec` are passed through.
+    ///
+    /// This is used by the `field_<name>` methods generated by `#[derive(Assertable)]` in the
+    /// `spectral-derive` crate, which build the subject name from the name of this `Spec`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&user).field("user.email", |user| &user.email).ends_with(&"@corp.com");
+    /// ```
+    #[track_caller]
+    pub fn field<F, T, N>(&self, subject_name: N, accessor: F) -> Spec<'s, T>
+        where F: FnOnce(&'s S) -> &'s T,
+              N: Into<Cow<'s, str>>
+    {
+        Spec {
+            subject: Subject::Borrowed(accessor(self.projected_subject("field"))),
+            subject_name: Some(subject_name.into()),
+            location: self.location.clone(),
+            description: self.description,
+            negated: self.negated,
+        }
+    }
+
+    /// Negates the assertions which follow, so that each fails if it would otherwise have passed.
+    /// This applies to every assertion chained after it.
+    ///
+    /// Assertions which return a new `Spec` (such as `is_some` or `contains_key`) cannot be
+    /// negated, and will panic if called after `not()`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").not().starts_with("W");
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        let mut spec = self;
+        spec.negated = !spec.negated;
 
         spec
     }
+
+    /// Inverts the negation of the spec. This is used to implement assertions which are the
+    /// opposite of another, such as `is_not_equal_to`, by inverting, asserting and then inverting
+    /// back again.
+    fn toggle_negation(&mut self) -> &mut Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    /// Returns the borrowed subject for assertions which return a new `Spec` for part of it,
+    /// panicking if the subject is owned by the `Spec`.
+    #[track_caller]
+    fn projected_subject(&self, assertion: &str) -> &'s S {
+        match self.borrowed_subject() {
+            Some(subject) => subject,
+            None => {
+                panic!("`{}` cannot be used on an owned subject, call `by_ref()` first",
+                       assertion)
+            }
+        }
+    }
+
+    /// Panics if the spec has been negated, for assertions which are unable to support it.
+    #[track_caller]
+    fn ensure_not_negated(&self, assertion: &str) {
+        if self.negated {
+            panic!("`{}` cannot be negated with `not()`", assertion);
+        }
+    }
 }
 
 impl<'s, S> Spec<'s, S>
@@ -417,19 +785,62 @@ impl<'s, S> Spec<'s, S>
     /// Asserts that the actual value and the expected value are equal. The value type must
     /// implement `PartialEq`.
     ///
+    /// If either value has a long `Debug` representation, both are pretty-printed and a
+    /// line-by-line diff is added to the panic message.
+    ///
     /// ```rust,ignore
     /// assert_that(&"hello").is_equal_to(&"hello");
     /// ```
-    pub fn is_equal_to<E: Borrow<S>>(&mut self, expected: E) {
-        let subject = self.subject;
+    #[track_caller]
+    pub fn is_equal_to<E: Borrow<S>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_expected = expected.borrow();
 
-        if !subject.eq(borrowed_expected) {
+        if self.passes(subject.eq(borrowed_expected)) {
+            return self;
+        }
+
+        if self.negated {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("<{:?}>", borrowed_expected))
-                .with_actual(format!("<{:?}>", subject))
+                .with_negated_expected(format!("<{:?}> to not equal <{:?}>",
+                                               subject,
+                                               borrowed_expected))
+                .with_actual("equal".to_string())
                 .fail();
+            return self;
         }
+
+        let expected_debug = format!("{:?}", borrowed_expected);
+        let actual_debug = format!("{:?}", subject);
+
+        if expected_debug.len() <= MAX_INLINE_VALUE_LENGTH &&
+           actual_debug.len() <= MAX_INLINE_VALUE_LENGTH {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("<{}>", expected_debug))
+                .with_actual(format!("<{}>", actual_debug))
+                .fail();
+            return self;
+        }
+
+        let expected_pretty = format!("{:#?}", borrowed_expected);
+        let actual_pretty = format!("{:#?}", subject);
+
+        let (expected_message, actual_message) =
+            if expected_pretty.lines().count() + actual_pretty.lines().count() >
+               MAX_PRETTY_VALUE_LINES {
+                ("<value shown in diff>".to_string(), "<value shown in diff>".to_string())
+            } else {
+                (indent_value(&expected_pretty), indent_value(&actual_pretty))
+            };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(expected_message)
+            .with_actual(actual_message)
+            .with_diff(&expected_pretty, &actual_pretty)
+            .fail();
+
+        self
     }
 
     /// Asserts that the actual value and the expected value are not equal. The value type must
@@ -438,19 +849,18 @@ impl<'s, S> Spec<'s, S>
     /// ```rust,ignore
     /// assert_that(&"hello").is_not_equal_to(&"hello");
     /// ```
-    pub fn is_not_equal_to<E: Borrow<S>>(&mut self, expected: E) {
-        let subject = self.subject;
-        let borrowed_expected = expected.borrow();
-
-        if subject.eq(borrowed_expected) {
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("<{:?}> to not equal <{:?}>", subject, borrowed_expected))
-                .with_actual(format!("equal"))
-                .fail();
-        }
+    #[track_caller]
+    pub fn is_not_equal_to<E: Borrow<S>>(&mut self, expected: E) -> &mut Self {
+        self.toggle_negation().is_equal_to(expected).toggle_negation()
     }
 }
 
+/// Wraps a multi-line value in angle brackets, indenting it to line up underneath the first line
+/// of the "expected" and "but was" labels.
+fn indent_value(value: &str) -> String {
+    format!("<{}>", value.replace("\n", "\n\t          "))
+}
+
 impl<'s, S> Spec<'s, S>
     where S: Debug
 {
@@ -458,20 +868,52 @@ impl<'s, S> Spec<'s, S>
     /// cause the assertion to fail.
     ///
     /// NOTE: The resultant panic message will only state the actual value. It's recommended that
-    /// you write your own assertion rather than relying upon this.
+    /// you use `satisfies` with a `Matcher`, or write your own assertion, rather than relying upon
+    /// this.
     ///
     /// ```rust,ignore
     /// assert_that(&"hello").matches(|x| x.eq(&"hello"));
     /// ```
-    pub fn matches<F>(&mut self, matching_function: F)
-        where F: Fn(&'s S) -> bool
+    #[track_caller]
+    pub fn matches<F>(&mut self, matching_function: F) -> &mut Self
+        where F: Fn(&S) -> bool
     {
-        let subject = self.subject;
+        let subject = &*self.subject;
 
-        if !matching_function(subject) {
+        if !self.passes(matching_function(subject)) {
+            let message = if self.negated {
+                format!("expectation unexpectedly met for value <{:?}>", subject)
+            } else {
+                format!("expectation failed for value <{:?}>", subject)
+            };
+
+            AssertionFailure::from_spec(self).fail_with_message(message);
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is matched by the provided `Matcher`. The failure message uses
+    /// the matcher's description of what was expected.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).satisfies(all_of((gt(3), lt(10))));
+    /// ```
+    #[track_caller]
+    pub fn satisfies<M>(&mut self, matcher: M) -> &mut Self
+        where M: Matcher<S>
+    {
+        let subject = &*self.subject;
+
+        if !self.passes(matcher.matches(subject)) {
             AssertionFailure::from_spec(self)
-                .fail_with_message(format!("expectation failed for value <{:?}>", subject));
+                .with_expected(format!("a value {}", matcher.describe()))
+                .with_negated_expected(format!("a value {}", matchers::not(&matcher).describe()))
+                .with_actual(matcher.describe_mismatch(subject))
+                .fail();
         }
+
+        self
     }
 
     /// Transforms the subject of the `Spec` by passing it through to the provided mapping
@@ -481,14 +923,37 @@ impl<'s, S> Spec<'s, S>
     /// let test_struct = TestStruct { value: 5 };
     /// assert_that(&test_struct).map(|val| &val.value).is_equal_to(&5);
     /// ```
+    #[track_caller]
     pub fn map<F, T>(self, mapping_function: F) -> Spec<'s, T>
         where F: Fn(&'s S) -> &'s T
     {
         Spec {
-            subject: mapping_function(self.subject),
+            subject: Subject::Borrowed(mapping_function(self.projected_subject("map"))),
             subject_name: self.subject_name,
-            location: self.location.clone(),
+            location: self.location,
             description: self.description,
+            negated: self.negated,
+        }
+    }
+
+    /// Transforms the subject of the `Spec` into a newly computed value, such as a length or a
+    /// parsed number, by passing it through to the provided mapping function.
+    ///
+    /// Unlike `map`, the mapping function returns an owned value rather than a reference. As with
+    /// `assert_that_owned`, the computed value is moved into the new `Spec`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").map_owned(|val| val.len()).is_equal_to(5);
+    /// ```
+    pub fn map_owned<F, T: 's>(self, mapping_function: F) -> Spec<'s, T>
+        where F: FnOnce(&S) -> T
+    {
+        Spec {
+            subject: Subject::Owned(mapping_function(&self.subject)),
+            subject_name: self.subject_name,
+            location: self.location,
+            description: self.description,
+            negated: self.negated,
         }
     }
 }
@@ -498,6 +963,9 @@ mod tests {
 
     use super::prelude::*;
 
+    use std::error::Error;
+    use std::rc::Rc;
+
     #[test]
     fn should_be_able_to_use_macro_form_with_deliberate_reference() {
         let test_vec = vec![1, 2, 3, 4, 5];
@@ -573,6 +1041,21 @@ mod tests {
         asserting!(&"closure").that(&value).matches(|val| val.eq(&"Hi"));
     }
 
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>\
+                   \n\n\tat location: src/lib.rs:")]
+    fn should_contain_file_and_line_in_panic_for_function_assertions() {
+        assert_that(&1).is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: option[some]\n\t but was: option[none]\
+                   \n\n\tat location: src/lib.rs:")]
+    fn should_contain_file_and_line_in_panic_for_chained_function_assertions() {
+        let value: Option<i32> = None;
+        assert_that(&value).is_some().is_equal_to(1);
+    }
+
     #[test]
     #[should_panic(expected = "\n\tfor subject [number one]\n\texpected: <2>\n\t but was: <1>\
                    \n\n\tat location: src/lib.rs:")]
@@ -606,6 +1089,61 @@ mod tests {
         assert_that(&1).is_equal_to(&2);
     }
 
+    #[test]
+    #[should_panic(expected = "\n\texpected: <LargeStruct {\
+                   \n\t              name: \"a fairly long name\",\
+                   \n\t              description: \"a description which is long enough\",\
+                   \n\t              value: 1,\
+                   \n\t          }>\
+                   \n\t but was: <LargeStruct {\
+                   \n\t              name: \"a fairly long name\",\
+                   \n\t              description: \"a description which is long enough\",\
+                   \n\t              value: 2,\
+                   \n\t          }>\
+                   \n\n\tdiff (- expected, + actual):\
+                   \n\t  LargeStruct {\
+                   \n\t      name: \"a fairly long name\",\
+                   \n\t      description: \"a description which is long enough\",\
+                   \n\t-     value: 1,\
+                   \n\t+     value: 2,\
+                   \n\t  }\n")]
+    fn should_show_diff_if_large_values_are_unequal() {
+        assert_that(&LargeStruct::new(2)).is_equal_to(LargeStruct::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <value shown in diff>\
+                   \n\t but was: <value shown in diff>\
+                   \n\n\tdiff (- expected, + actual):\
+                   \n\t  ...\
+                   \n\t      48,\
+                   \n\t      49,\
+                   \n\t      50,\
+                   \n\t-     51,\
+                   \n\t+     0,\
+                   \n\t      52,\
+                   \n\t      53,\
+                   \n\t      54,\
+                   \n\t  ...\n")]
+    fn should_only_show_diff_if_values_are_very_large() {
+        let expected: Vec<usize> = (0..100).collect();
+        let mut actual = expected.clone();
+        actual[51] = 0;
+
+        assert_that(&actual).is_equal_to(expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tdiff (- expected, + actual):\
+                   \n\t- \"the quick brown fox jumps over the lazy dog and keeps on running\"\
+                   \n\t+ \"the quick brown cat jumps over the lazy dog and keeps on running\"\n")]
+    fn should_show_diff_if_long_strings_are_unequal() {
+        let value = "the quick brown cat jumps over the lazy dog and keeps on running";
+        let expected = "the quick brown fox jumps over the lazy dog and keeps on running";
+
+        assert_that(&value).is_equal_to(expected);
+    }
+
     #[test]
     fn is_not_equal_to_should_support_multiple_borrow_forms() {
         assert_that(&1).is_not_equal_to(2);
@@ -624,6 +1162,48 @@ mod tests {
         assert_that(&1).is_not_equal_to(&1);
     }
 
+    #[test]
+    #[should_panic(expected = "\n\texpected: <3>\n\t but was: <1>")]
+    fn should_allow_assertions_to_be_chained() {
+        assert_that(&1).is_equal_to(1).is_not_equal_to(2).is_equal_to(3);
+    }
+
+    #[test]
+    fn should_apply_negation_to_every_chained_assertion() {
+        assert_that(&1).not().is_equal_to(2).is_equal_to(3);
+    }
+
+    #[test]
+    fn should_not_panic_if_negated_assertion_fails() {
+        assert_that(&1).not().is_equal_to(2);
+        assert_that(&1).not().is_not_equal_to(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <1> to not equal <1>\n\t but was: equal")]
+    fn should_panic_if_negated_assertion_passes() {
+        assert_that(&1).not().is_equal_to(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_cancel_out_double_negation() {
+        assert_that(&1).not().not().is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpectation unexpectedly met for value <\"Hello\">")]
+    fn should_panic_if_negated_value_matches() {
+        let value = "Hello";
+        assert_that(&value).not().matches(|val| val.eq(&"Hello"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`is_some` cannot be negated with `not()`")]
+    fn should_panic_if_assertion_returning_spec_is_negated() {
+        assert_that(&Some(1)).not().is_some();
+    }
+
     #[test]
     fn should_not_panic_if_value_matches() {
         let value = "Hello";
@@ -643,9 +1223,134 @@ mod tests {
         assert_that(&test_struct).map(|val| &val.value).is_equal_to(&5);
     }
 
+    #[test]
+    fn should_be_able_to_map_to_computed_value() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).map_owned(|val| val.iter().sum::<i32>()).is_equal_to(6);
+        assert_that(&"42").map_owned(|val| val.parse::<u32>()).is_ok_containing(42);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [length]\n\texpected: <4>\n\t but was: <5>")]
+    fn should_keep_subject_name_when_mapping_to_computed_value() {
+        assert_that(&"Hello").named("length").map_owned(|val| val.len()).is_equal_to(4);
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_owned_subjects() {
+        assert_that_owned(vec![1, 2, 3]).has_length(3).contains(2);
+        asserting("owned").that_owned("Hello".to_string()).starts_with("H");
+        assert_that_owned(Some(5)).by_ref().is_some().is_equal_to(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "`is_some` cannot be used on an owned subject, \
+                               call `by_ref()` first")]
+    fn should_panic_if_assertion_returning_spec_is_used_on_owned_subject() {
+        assert_that_owned(Some(5)).is_some();
+    }
+
+    #[test]
+    fn should_drop_owned_subjects_along_with_spec() {
+        let value = Rc::new(5);
+
+        assert_that_owned(Rc::clone(&value)).is_equal_to(Rc::new(5));
+        assert_that(&1).map_owned(|_| Rc::clone(&value)).is_equal_to(Rc::new(5));
+
+        assert_that(&Rc::strong_count(&value)).is_equal_to(1);
+    }
+
+    #[test]
+    fn check_that_should_return_value_if_assertions_pass() {
+        let result = check_that(&Some(5), |spec| *spec.is_some().subject);
+        assert_that(&result).is_ok_containing(5);
+    }
+
+    #[test]
+    fn check_that_should_return_error_if_assertion_fails() {
+        let error = check_that(&1, |spec| {
+                spec.is_equal_to(2);
+            })
+            .unwrap_err();
+
+        assert_that(&error.expected).contains_value("<2>".to_string());
+        assert_that(&error.actual).contains_value("<1>".to_string());
+        assert_that(&error.subject_name).is_none();
+        assert_that(&error.description).is_none();
+        assert_that(&error.message).is_none();
+    }
+
+    #[test]
+    fn check_that_should_return_first_failure_if_several_assertions_fail() {
+        let error = check_that(&1, |spec| {
+                spec.is_equal_to(2);
+                spec.is_equal_to(3);
+            })
+            .unwrap_err();
+
+        assert_that(&error.expected).contains_value("<2>".to_string());
+    }
+
+    #[test]
+    fn check_that_should_return_error_if_assertion_cannot_continue() {
+        let option: Option<u8> = None;
+        let error = check_that(&option, |spec| {
+                spec.is_some().is_equal_to(1);
+            })
+            .unwrap_err();
+
+        assert_that(&error.expected).contains_value("option[some]".to_string());
+    }
+
+    #[test]
+    fn check_that_should_return_error_with_message_if_message_is_provided() {
+        let error = check_that(&"Hello", |spec| {
+                spec.matches(|val| val.eq(&"Hi"));
+            })
+            .unwrap_err();
+
+        assert_that(&error.message)
+            .contains_value("expectation failed for value <\"Hello\">".to_string());
+    }
+
+    #[test]
+    fn assertion_error_should_display_as_failure_message() {
+        let error = check_that(&1, |spec| {
+                spec.is_equal_to(2);
+            })
+            .unwrap_err();
+        let error: Box<dyn Error> = Box::new(error);
+
+        assert_that(&error.to_string())
+            .starts_with("\n\texpected: <2>\n\t but was: <1>\n\n\tat location: src/lib.rs:");
+    }
+
+    #[test]
+    #[should_panic(expected = "something went wrong")]
+    fn check_that_should_not_catch_other_panics() {
+        let _ = check_that(&1, |_| panic!("something went wrong"));
+    }
+
     #[derive(Debug, PartialEq)]
     struct TestStruct {
         pub value: u8,
     }
 
+    #[derive(Debug, PartialEq)]
+    struct LargeStruct {
+        name: &'static str,
+        description: &'static str,
+        value: u8,
+    }
+
+    impl LargeStruct {
+        fn new(value: u8) -> LargeStruct {
+            LargeStruct {
+                name: "a fairly long name",
+                description: "a description which is long enough",
+                value,
+            }
+        }
+    }
+
 }
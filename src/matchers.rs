@@ -0,0 +1,441 @@
+//! Reusable matchers which can be composed and passed to `Spec::satisfies`.
+//!
+//! ```rust,ignore
+//! use spectral::matchers::{all_of, gt, lt};
+//!
+//! assert_that(&5).satisfies(all_of((gt(3), lt(10))));
+//! ```
+
+use std::fmt::Debug;
+
+/// A reusable expectation against a value.
+///
+/// `describe` should complete the phrase "a value ...", for example "greater than <3>", so that
+/// matchers read naturally when they are combined.
+pub trait Matcher<T: Debug + ?Sized> {
+    /// Returns true if the value meets the expectation.
+    fn matches(&self, actual: &T) -> bool;
+
+    /// Describes the expectation, for use in the "expected" part of a failure message.
+    fn describe(&self) -> String;
+
+    /// Describes why the value did not meet the expectation, for use in the "but was" part of a
+    /// failure message.
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual)
+    }
+
+    /// Returns true if the description joins several matchers with "and" or "or", so that it
+    /// needs brackets when used inside another combination.
+    fn is_combination(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Debug + ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for &M {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+
+    fn is_combination(&self) -> bool {
+        (**self).is_combination()
+    }
+}
+
+impl<T: Debug + ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+
+    fn is_combination(&self) -> bool {
+        (**self).is_combination()
+    }
+}
+
+/// A group of matchers which can be passed to `all_of` or `any_of`.
+///
+/// This is implemented for tuples of up to six matchers, and for a `Vec` of boxed matchers.
+pub trait MatcherList<T: Debug + ?Sized> {
+    fn matchers(&self) -> Vec<&dyn Matcher<T>>;
+}
+
+impl<T: Debug + ?Sized> MatcherList<T> for Vec<Box<dyn Matcher<T>>> {
+    fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+        self.iter().map(|matcher| &**matcher).collect()
+    }
+}
+
+macro_rules! impl_matcher_list {
+    ($($name:ident: $index:tt),+) => {
+        impl<T: Debug + ?Sized, $($name: Matcher<T>),+> MatcherList<T> for ($($name,)+) {
+            fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+                vec![$(&self.$index as &dyn Matcher<T>),+]
+            }
+        }
+    };
+}
+
+impl_matcher_list!(A: 0);
+impl_matcher_list!(A: 0, B: 1);
+impl_matcher_list!(A: 0, B: 1, C: 2);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Matches if every one of the provided matchers matches.
+#[derive(Debug)]
+pub struct AllOf<L> {
+    matchers: L,
+}
+
+/// Creates a matcher which matches if every one of the provided matchers matches.
+///
+/// ```rust,ignore
+/// assert_that(&5).satisfies(all_of((gt(3), lt(10))));
+/// ```
+pub fn all_of<L>(matchers: L) -> AllOf<L> {
+    AllOf { matchers }
+}
+
+impl<T: Debug + ?Sized, L: MatcherList<T>> Matcher<T> for AllOf<L> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.matchers().iter().all(|matcher| matcher.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.matchers.matchers(), " and ")
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        match self.matchers.matchers().iter().find(|matcher| !matcher.matches(actual)) {
+            Some(matcher) => matcher.describe_mismatch(actual),
+            None => format!("<{:?}>", actual),
+        }
+    }
+
+    fn is_combination(&self) -> bool {
+        self.matchers.matchers().len() > 1
+    }
+}
+
+/// Matches if at least one of the provided matchers matches.
+#[derive(Debug)]
+pub struct AnyOf<L> {
+    matchers: L,
+}
+
+/// Creates a matcher which matches if at least one of the provided matchers matches.
+///
+/// ```rust,ignore
+/// assert_that(&0).satisfies(any_of((eq(0), gt(10))));
+/// ```
+pub fn any_of<L>(matchers: L) -> AnyOf<L> {
+    AnyOf { matchers }
+}
+
+impl<T: Debug + ?Sized, L: MatcherList<T>> Matcher<T> for AnyOf<L> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.matchers().iter().any(|matcher| matcher.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.matchers.matchers(), " or ")
+    }
+
+    fn is_combination(&self) -> bool {
+        self.matchers.matchers().len() > 1
+    }
+}
+
+/// Matches if the provided matcher does not match.
+#[derive(Debug)]
+pub struct Not<M> {
+    matcher: M,
+}
+
+/// Creates a matcher which inverts the provided matcher.
+///
+/// ```rust,ignore
+/// assert_that(&5).satisfies(not(eq(3)));
+/// ```
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not { matcher }
+}
+
+impl<T: Debug + ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.matcher.matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", group(&self.matcher))
+    }
+}
+
+/// Matches values equal to the expected value.
+#[derive(Debug)]
+pub struct EqualTo<E> {
+    expected: E,
+}
+
+/// Creates a matcher which matches values equal to the expected value.
+pub fn eq<E>(expected: E) -> EqualTo<E> {
+    EqualTo { expected }
+}
+
+impl<T: Debug + PartialEq<E> + ?Sized, E: Debug> Matcher<T> for EqualTo<E> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.eq(&self.expected)
+    }
+
+    fn describe(&self) -> String {
+        format!("equal to <{:?}>", self.expected)
+    }
+}
+
+macro_rules! ordering_matcher {
+    ($(#[$attr:meta])* $name:ident, $function:ident, $description:expr, $op:tt) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<E> {
+            expected: E,
+        }
+
+        $(#[$attr])*
+        pub fn $function<E>(expected: E) -> $name<E> {
+            $name { expected }
+        }
+
+        impl<T: Debug + PartialOrd<E> + ?Sized, E: Debug> Matcher<T> for $name<E> {
+            fn matches(&self, actual: &T) -> bool {
+                *actual $op self.expected
+            }
+
+            fn describe(&self) -> String {
+                format!("{} <{:?}>", $description, self.expected)
+            }
+        }
+    };
+}
+
+ordering_matcher!(
+    /// Matches values less than the expected value.
+    LessThan, lt, "less than", <);
+ordering_matcher!(
+    /// Matches values less than or equal to the expected value.
+    LessThanOrEqualTo, le, "less than or equal to", <=);
+ordering_matcher!(
+    /// Matches values greater than the expected value.
+    GreaterThan, gt, "greater than", >);
+ordering_matcher!(
+    /// Matches values greater than or equal to the expected value.
+    GreaterThanOrEqualTo, ge, "greater than or equal to", >=);
+
+/// Matches collections which contain the expected item.
+#[derive(Debug)]
+pub struct Contains<E> {
+    expected: E,
+}
+
+/// Creates a matcher which matches collections containing the expected item.
+///
+/// ```rust,ignore
+/// assert_that(&vec![1, 2, 3]).satisfies(contains(2));
+/// ```
+pub fn contains<E>(expected: E) -> Contains<E> {
+    Contains { expected }
+}
+
+impl<T, E> Matcher<T> for Contains<E>
+    where T: Debug + ?Sized,
+          for<'a> &'a T: IntoIterator<Item = &'a E>,
+          E: Debug + PartialEq
+{
+    fn matches(&self, actual: &T) -> bool {
+        actual.into_iter().any(|item| item.eq(&self.expected))
+    }
+
+    fn describe(&self) -> String {
+        format!("containing <{:?}>", self.expected)
+    }
+}
+
+/// Matches strings which contain the expected substring.
+#[derive(Debug)]
+pub struct ContainsStr<'e> {
+    expected: &'e str,
+}
+
+/// Creates a matcher which matches strings containing the expected substring.
+///
+/// ```rust,ignore
+/// assert_that(&"Hello").satisfies(contains_str("ell"));
+/// ```
+pub fn contains_str(expected: &str) -> ContainsStr<'_> {
+    ContainsStr { expected }
+}
+
+impl<'e, T: AsRef<str> + Debug + ?Sized> Matcher<T> for ContainsStr<'e> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().contains(self.expected)
+    }
+
+    fn describe(&self) -> String {
+        format!("containing <{:?}>", self.expected)
+    }
+}
+
+/// Matches values for which the provided function returns true.
+pub struct Predicate<'d, F> {
+    description: &'d str,
+    function: F,
+}
+
+/// Creates a matcher from a description and a function. The description should complete the
+/// phrase "a value ...".
+///
+/// ```rust,ignore
+/// let even = predicate("that is even", |value: &i32| value % 2 == 0);
+/// assert_that(&4).satisfies(&even);
+/// ```
+pub fn predicate<F>(description: &str, function: F) -> Predicate<'_, F> {
+    Predicate {
+        description,
+        function,
+    }
+}
+
+impl<'d, T: Debug + ?Sized, F: Fn(&T) -> bool> Matcher<T> for Predicate<'d, F> {
+    fn matches(&self, actual: &T) -> bool {
+        (self.function)(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.description.to_string()
+    }
+}
+
+fn join_descriptions<T: Debug + ?Sized>(matchers: &[&dyn Matcher<T>], separator: &str) -> String {
+    let descriptions: Vec<String> = if matchers.len() > 1 {
+        matchers.iter().map(|matcher| group(matcher)).collect()
+    } else {
+        matchers.iter().map(|matcher| matcher.describe()).collect()
+    };
+
+    descriptions.join(separator)
+}
+
+/// Describes the matcher, wrapped in brackets if it is a combination of matchers, so that it is not
+/// ambiguous when used inside another combination.
+fn group<T: Debug + ?Sized, M: Matcher<T> + ?Sized>(matcher: &M) -> String {
+    if matcher.is_combination() {
+        format!("({})", matcher.describe())
+    } else {
+        matcher.describe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::*;
+
+    #[test]
+    fn should_not_panic_if_all_matchers_match() {
+        assert_that(&5).satisfies(all_of((gt(3), lt(10))));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value greater than <3> and less than <10>\
+                   \n\t but was: <12>")]
+    fn should_panic_if_one_of_all_matchers_does_not_match() {
+        assert_that(&12).satisfies(all_of((gt(3), lt(10))));
+    }
+
+    #[test]
+    fn should_not_panic_if_any_matcher_matches() {
+        assert_that(&0).satisfies(any_of((eq(0), gt(10))));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value equal to <0> or greater than <10>\
+                   \n\t but was: <5>")]
+    fn should_panic_if_no_matchers_match() {
+        assert_that(&5).satisfies(any_of((eq(0), gt(10))));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value not equal to <5>\n\t but was: <5>")]
+    fn should_panic_if_negated_matcher_matches() {
+        assert_that(&5).satisfies(not(eq(5)));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value (greater than <3> and less than <10>) \
+                   or equal to <0>\n\t but was: <12>")]
+    fn should_group_nested_combinations_in_description() {
+        assert_that(&12).satisfies(any_of((all_of((gt(3), lt(10))), eq(0))));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value that is even and positive or equal to <0>\
+                   \n\t but was: <-2>")]
+    fn should_not_group_descriptions_which_are_not_combinations() {
+        let even_and_positive = predicate("that is even and positive",
+                                          |value: &i32| value % 2 == 0 && *value > 0);
+
+        assert_that(&-2).satisfies(any_of((even_and_positive, eq(0))));
+    }
+
+    #[test]
+    fn should_support_inclusive_ordering_matchers() {
+        assert_that(&5).satisfies(all_of((ge(5), le(5))));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value containing <4>\n\t but was: <[1, 2, 3]>")]
+    fn should_panic_if_collection_does_not_contain_item() {
+        assert_that(&vec![1, 2, 3]).satisfies(contains(2));
+        assert_that(&vec![1, 2, 3]).satisfies(contains(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value containing <\"Bye\">\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_string_does_not_contain_substring() {
+        assert_that(&"Hello").satisfies(contains_str("ell"));
+        assert_that(&"Hello".to_string()).satisfies(contains_str("Bye"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a value that is even\n\t but was: <3>")]
+    fn should_allow_reusable_predicate_matchers() {
+        let even = predicate("that is even", |value: &i32| value % 2 == 0);
+
+        assert_that(&4).satisfies(&even);
+        assert_that(&3).satisfies(&even);
+    }
+
+    #[test]
+    fn should_allow_boxed_matchers_to_be_combined() {
+        let matchers: Vec<Box<dyn Matcher<i32>>> = vec![Box::new(gt(3)), Box::new(not(eq(4)))];
+        assert_that(&5).satisfies(all_of(matchers));
+    }
+}
@@ -1,19 +1,41 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec};
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::cmp::PartialOrd;
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
+use std::ops::RangeBounds;
 
 #[cfg(feature = "num")]
-use num::Float;
+use std::mem;
+
+#[cfg(feature = "num")]
+use num::{Float, Integer, NumCast, Signed, Zero};
 
 pub trait OrderedAssertions<T>
     where T: Debug + PartialOrd
 {
-    fn is_less_than<E: Borrow<T>>(&mut self, other: E);
-    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E);
-    fn is_greater_than<E: Borrow<T>>(&mut self, other: E);
-    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E);
+    fn is_less_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_in_range<R: RangeBounds<T>>(&mut self, range: R) -> &mut Self;
+    fn is_not_in_range<R: RangeBounds<T>>(&mut self, range: R) -> &mut Self;
+    fn is_between<L: Borrow<T>, H: Borrow<T>>(&mut self, low: L, high: H, ends: RangeEnds)
+                                              -> &mut Self;
+}
+
+/// Which ends of the range are included by `is_between`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeEnds {
+    /// Both `low` and `high` are in the range, as in `low..=high`.
+    Inclusive,
+    /// Neither `low` nor `high` are in the range.
+    Exclusive,
+    /// Only `low` is in the range, as in `low..high`.
+    InclusiveLow,
+    /// Only `high` is in the range.
+    InclusiveHigh,
 }
 
 impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
@@ -25,16 +47,20 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&1).is_less_than(&2);
     /// ```
-    fn is_less_than<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_less_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_other = other.borrow();
 
-        if subject >= borrowed_other {
+        if !self.passes(subject < borrowed_other) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("value less than <{:?}>", borrowed_other))
+                .with_negated_expected(format!("value not less than <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is less than or equal to the expected value. The subject type
@@ -43,16 +69,21 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_less_than_or_equal_to(&2);
     /// ```
-    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_other = other.borrow();
 
-        if subject > borrowed_other {
+        if !self.passes(subject <= borrowed_other) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("value less than or equal to <{:?}>", borrowed_other))
+                .with_negated_expected(format!("value not less than or equal to <{:?}>",
+                                               borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is greater than the expected value. The subject type must
@@ -61,16 +92,20 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than(&1);
     /// ```
-    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_other = other.borrow();
 
-        if subject <= borrowed_other {
+        if !self.passes(subject > borrowed_other) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("value greater than <{:?}>", borrowed_other))
+                .with_negated_expected(format!("value not greater than <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is greater than or equal to the expected value. The subject type
@@ -79,22 +114,403 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than_or_equal_to(&1);
     /// ```
-    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = &*self.subject;
         let borrowed_other = other.borrow();
 
-        if subject < borrowed_other {
+        if !self.passes(subject >= borrowed_other) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("value greater than or equal to <{:?}>", borrowed_other))
+                .with_negated_expected(format!("value not greater than or equal to <{:?}>",
+                                               borrowed_other))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is within the given range, which can be any kind of range such
+    /// as `1..5`, `1..=5`, `..5` or `1..`. The subject type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&15).is_in_range(10..=20);
+    /// ```
+    #[track_caller]
+    fn is_in_range<R: RangeBounds<T>>(&mut self, range: R) -> &mut Self {
+        is_in_range(self, range.start_bound(), range.end_bound());
+
+        self
+    }
+
+    /// Asserts that the subject is not within the given range. The subject type must implement
+    /// `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).is_not_in_range(..0);
+    /// ```
+    #[track_caller]
+    fn is_not_in_range<R: RangeBounds<T>>(&mut self, range: R) -> &mut Self {
+        self.toggle_negation().is_in_range(range).toggle_negation()
+    }
+
+    /// Asserts that the subject is between `low` and `high`, with `ends` deciding whether each
+    /// of them is part of the range. The subject type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&0.5).is_between(0.0, 1.0, RangeEnds::Exclusive);
+    /// ```
+    #[track_caller]
+    fn is_between<L: Borrow<T>, H: Borrow<T>>(&mut self, low: L, high: H, ends: RangeEnds)
+                                              -> &mut Self {
+        let (low, high) = (low.borrow(), high.borrow());
+
+        let (start, end) = match ends {
+            RangeEnds::Inclusive => (Included(low), Included(high)),
+            RangeEnds::Exclusive => (Excluded(low), Excluded(high)),
+            RangeEnds::InclusiveLow => (Included(low), Excluded(high)),
+            RangeEnds::InclusiveHigh => (Excluded(low), Included(high)),
+        };
+
+        is_in_range(self, start, end);
+
+        self
+    }
+}
+
+#[track_caller]
+fn is_in_range<'s, T>(spec: &Spec<'s, T>, start: Bound<&T>, end: Bound<&T>)
+    where T: Debug + PartialOrd
+{
+    let subject = &*spec.subject;
+
+    let violated_bound = match start {
+        Included(low) | Excluded(low) if subject.partial_cmp(low).is_none() => {
+            Some(format!("not comparable with the lower bound <{:?}>", low))
+        }
+        Included(low) if subject < low => Some(format!("below the lower bound <{:?}>", low)),
+        Excluded(low) if subject <= low => {
+            Some(format!("not above the exclusive lower bound <{:?}>", low))
+        }
+        _ => None,
+    };
+
+    let violated_bound = violated_bound.or_else(|| match end {
+        Included(high) | Excluded(high) if subject.partial_cmp(high).is_none() => {
+            Some(format!("not comparable with the upper bound <{:?}>", high))
+        }
+        Included(high) if subject > high => {
+            Some(format!("above the upper bound <{:?}>", high))
+        }
+        Excluded(high) if subject >= high => {
+            Some(format!("not below the exclusive upper bound <{:?}>", high))
+        }
+        _ => None,
+    });
+
+    if !spec.passes(violated_bound.is_none()) {
+        let range = describe_range(start, end);
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("value in range {}", range))
+            .with_negated_expected(format!("value not in range {}", range))
+            .with_actual(match violated_bound {
+                Some(bound) => format!("<{:?}> ({})", subject, bound),
+                None => format!("<{:?}>", subject),
+            })
+            .fail();
+    }
+}
+
+/// Renders the range as it would be written in Rust. Ranges which exclude their start have no
+/// range syntax, so the excluded start is rendered as an inclusive one and noted afterwards.
+fn describe_range<T: Debug>(start: Bound<&T>, end: Bound<&T>) -> String {
+    let range = match (start, end) {
+        (Unbounded, Unbounded) => "..".to_string(),
+        (Unbounded, Excluded(high)) => format!("..{:?}", high),
+        (Unbounded, Included(high)) => format!("..={:?}", high),
+        (Included(low), Unbounded) | (Excluded(low), Unbounded) => format!("{:?}..", low),
+        (Included(low), Excluded(high)) | (Excluded(low), Excluded(high)) => {
+            format!("{:?}..{:?}", low, high)
+        }
+        (Included(low), Included(high)) | (Excluded(low), Included(high)) => {
+            format!("{:?}..={:?}", low, high)
+        }
+    };
+
+    match start {
+        Excluded(low) => format!("<{}> excluding <{:?}>", range, low),
+        _ => format!("<{}>", range),
+    }
+}
+
+#[cfg(feature = "num")]
+pub trait SignedAssertions<T: Signed> {
+    fn is_positive(&mut self) -> &mut Self;
+    fn is_negative(&mut self) -> &mut Self;
+}
+
+#[cfg(feature = "num")]
+impl<'s, T> SignedAssertions<T> for Spec<'s, T>
+    where T: Signed + PartialOrd + Debug
+{
+    /// Asserts that the subject is greater than zero. Unlike `Signed::is_positive`, this does
+    /// not treat `0.0` or `NaN` as positive.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).is_positive();
+    /// ```
+    #[track_caller]
+    fn is_positive(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+
+        if !self.passes(*subject > T::zero()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("positive value".to_string())
+                .with_negated_expected("non-positive value".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is less than zero. Unlike `Signed::is_negative`, this does not
+    /// treat `-0.0` or `NaN` as negative.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&-5).is_negative();
+    /// ```
+    #[track_caller]
+    fn is_negative(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+
+        if !self.passes(*subject < T::zero()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("negative value".to_string())
+                .with_negated_expected("non-negative value".to_string())
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+pub trait ZeroAssertions<T: Zero> {
+    fn is_zero(&mut self) -> &mut Self;
+    fn is_not_zero(&mut self) -> &mut Self;
+}
+
+#[cfg(feature = "num")]
+impl<'s, T: Zero + Debug> ZeroAssertions<T> for Spec<'s, T> {
+    /// Asserts that the subject is zero.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&0).is_zero();
+    /// ```
+    #[track_caller]
+    fn is_zero(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+
+        if !self.passes(subject.is_zero()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("value equal to zero".to_string())
+                .with_negated_expected("value not equal to zero".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is not zero.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1).is_not_zero();
+    /// ```
+    #[track_caller]
+    fn is_not_zero(&mut self) -> &mut Self {
+        self.toggle_negation().is_zero().toggle_negation()
+    }
+}
+
+#[cfg(feature = "num")]
+pub trait IntegerAssertions<T: Integer> {
+    fn is_even(&mut self) -> &mut Self;
+    fn is_odd(&mut self) -> &mut Self;
+    fn is_divisible_by<E: Borrow<T>>(&mut self, divisor: E) -> &mut Self;
+}
+
+#[cfg(feature = "num")]
+impl<'s, T: Integer + Debug> IntegerAssertions<T> for Spec<'s, T> {
+    /// Asserts that the subject is even.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&4).is_even();
+    /// ```
+    #[track_caller]
+    fn is_even(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+
+        if !self.passes(subject.is_even()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("even value".to_string())
+                .with_negated_expected("odd value".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is odd.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&3).is_odd();
+    /// ```
+    #[track_caller]
+    fn is_odd(&mut self) -> &mut Self {
+        self.toggle_negation().is_even().toggle_negation()
+    }
+
+    /// Asserts that the subject is divisible by the expected divisor. Only zero is considered
+    /// to be divisible by zero.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&12).is_divisible_by(3);
+    /// ```
+    #[track_caller]
+    fn is_divisible_by<E: Borrow<T>>(&mut self, divisor: E) -> &mut Self {
+        let subject = &*self.subject;
+        let borrowed_divisor = divisor.borrow();
+
+        if !self.passes(subject.is_multiple_of(borrowed_divisor)) {
+            let actual = if borrowed_divisor.is_zero() {
+                format!("<{:?}>", subject)
+            } else {
+                format!("<{:?}> (remainder <{:?}>)",
+                        subject,
+                        subject.div_rem(borrowed_divisor).1)
+            };
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("value divisible by <{:?}>", borrowed_divisor))
+                .with_negated_expected(format!("value not divisible by <{:?}>", borrowed_divisor))
+                .with_actual(actual)
+                .fail();
+        }
+
+        self
+    }
+}
+
+/// The tolerances used by `is_close_to_with`, which are combined in the same way as Python's
+/// `math.isclose`: values are close if their difference is within the relative tolerance of the
+/// larger of the two, or within the absolute tolerance.
+///
+/// By default the relative tolerance is `1e-9` and the absolute tolerance is zero.
+///
+/// ```rust,ignore
+/// Tolerance::new().relative(1e-6).absolute(1e-12)
+/// ```
+#[cfg(feature = "num")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance<T> {
+    relative: T,
+    absolute: T,
+}
+
+#[cfg(feature = "num")]
+impl<T: Float> Tolerance<T> {
+    pub fn new() -> Tolerance<T> {
+        Tolerance {
+            relative: <T as NumCast>::from(1e-9).unwrap_or_else(T::epsilon),
+            absolute: T::zero(),
+        }
+    }
+
+    /// Sets the maximum difference allowed, relative to the larger of the two values.
+    pub fn relative(mut self, relative: T) -> Tolerance<T> {
+        self.relative = relative;
+        self
+    }
+
+    /// Sets the maximum difference allowed regardless of the size of the values, which is
+    /// needed when comparing against zero.
+    pub fn absolute(mut self, absolute: T) -> Tolerance<T> {
+        self.absolute = absolute;
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T: Float> Default for Tolerance<T> {
+    fn default() -> Tolerance<T> {
+        Tolerance::new()
+    }
+}
+
+/// A subject type which can be checked with `is_close_to`, taking an expected value and a
+/// tolerance of the same type. This is implemented for `f32` and `f64` (with the `num` feature)
+/// and for `Duration`.
+///
+/// `is_close_to` is provided as a method of `Spec` for these types, rather than by a trait for
+/// each of them, so that it is not ambiguous when the subject type is yet to be inferred, as in
+/// `assert_that(&Float::nan()).is_close_to(1.0f64, 0.01f64)`.
+pub trait CloseToSubject: Sized {
+    /// Fails the assertion unless the subject is within the tolerance of the expected value.
+    fn assert_close_to(spec: &mut Spec<Self>, expected: &Self, tolerance: &Self);
+}
+
+impl<'s, S: CloseToSubject> Spec<'s, S> {
+    /// Asserts that the subject is close to the expected value, within the provided tolerance.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&2.0f64).is_close_to(2.0f64, 0.01f64);
+    /// assert_that(&elapsed).is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    /// ```
+    #[track_caller]
+    pub fn is_close_to<E: Borrow<S>, O: Borrow<S>>(&mut self, expected: E, tolerance: O)
+                                                   -> &mut Self {
+        S::assert_close_to(self, expected.borrow(), tolerance.borrow());
+
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+impl CloseToSubject for f32 {
+    #[track_caller]
+    fn assert_close_to(spec: &mut Spec<f32>, expected: &f32, tolerance: &f32) {
+        FloatAssertions::is_close_to(spec, expected, tolerance);
+    }
+}
+
+#[cfg(feature = "num")]
+impl CloseToSubject for f64 {
+    #[track_caller]
+    fn assert_close_to(spec: &mut Spec<f64>, expected: &f64, tolerance: &f64) {
+        FloatAssertions::is_close_to(spec, expected, tolerance);
     }
 }
 
 #[cfg(feature = "num")]
 pub trait FloatAssertions<T: Float> {
-    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O);
+    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) -> &mut Self;
+    fn is_close_to_relative<E: Borrow<T>, O: Borrow<T>>(&mut self,
+                                                        expected: E,
+                                                        relative_tolerance: O)
+                                                        -> &mut Self;
+    fn is_close_to_with<E: Borrow<T>>(&mut self, expected: E, tolerance: Tolerance<T>)
+                                      -> &mut Self;
+    fn is_within_ulps<E: Borrow<T>>(&mut self, expected: E, max_ulps: u64) -> &mut Self;
+    fn is_nan(&mut self) -> &mut Self;
+    fn is_not_nan(&mut self) -> &mut Self;
+    fn is_finite(&mut self) -> &mut Self;
+    fn is_infinite(&mut self) -> &mut Self;
 }
 
 #[cfg(feature = "num")]
@@ -105,21 +521,232 @@ impl<'s, T: Float + Debug> FloatAssertions<T> for Spec<'s, T> {
     /// ```rust,ignore
     /// assert_that(&2.0f64).is_close_to(2.0f64, 0.01f64);
     /// ```
-    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) {
+    #[track_caller]
+    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_expected = *expected.borrow();
+        let borrowed_tolerance = *tolerance.borrow();
+
+        let difference = (subject - borrowed_expected).abs();
+
+        is_close(self,
+                 borrowed_expected,
+                 difference <= borrowed_tolerance.abs(),
+                 format!("tolerance of <{:?}>", borrowed_tolerance));
+
+        self
+    }
+
+    /// Asserts that the subject is close to the expected value, with the difference allowed
+    /// being relative to the larger of the two values. This is useful when the values could be
+    /// of any magnitude, but cannot be used to compare against zero.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1e9f64).is_close_to_relative(1.000001e9f64, 1e-6f64);
+    /// ```
+    #[track_caller]
+    fn is_close_to_relative<E: Borrow<T>, O: Borrow<T>>(&mut self,
+                                                        expected: E,
+                                                        relative_tolerance: O)
+                                                        -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_expected = *expected.borrow();
+        let borrowed_tolerance = *relative_tolerance.borrow();
+
+        let difference = (subject - borrowed_expected).abs();
+        let largest = subject.abs().max(borrowed_expected.abs());
+
+        is_close(self,
+                 borrowed_expected,
+                 difference <= borrowed_tolerance.abs() * largest,
+                 format!("relative tolerance of <{:?}>", borrowed_tolerance));
+
+        self
+    }
+
+    /// Asserts that the subject is close to the expected value, using both a relative and an
+    /// absolute tolerance in the same way as Python's `math.isclose`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&measured).is_close_to_with(0.0, Tolerance::new().absolute(1e-12));
+    /// ```
+    #[track_caller]
+    fn is_close_to_with<E: Borrow<T>>(&mut self, expected: E, tolerance: Tolerance<T>)
+                                      -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_expected = *expected.borrow();
+
+        let difference = (subject - borrowed_expected).abs();
+        let largest = subject.abs().max(borrowed_expected.abs());
+        let allowed = (tolerance.relative.abs() * largest).max(tolerance.absolute.abs());
+
+        is_close(self,
+                 borrowed_expected,
+                 difference <= allowed,
+                 format!("relative tolerance of <{:?}>, absolute tolerance of <{:?}>",
+                         tolerance.relative,
+                         tolerance.absolute));
+
+        self
+    }
+
+    /// Asserts that the subject is within the given number of units in the last place (ULPs)
+    /// of the expected value, which is the number of representable floats between them. This
+    /// is supported for `f32` and `f64`. Zero and negative zero are treated as equal.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&(0.1f64 + 0.2f64)).is_within_ulps(0.3f64, 1);
+    /// ```
+    #[track_caller]
+    fn is_within_ulps<E: Borrow<T>>(&mut self, expected: E, max_ulps: u64) -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_expected = *expected.borrow();
+
+        let within_ulps = !borrowed_expected.is_nan() &&
+                          ulp_distance(subject, borrowed_expected) <= max_ulps;
+
+        is_close(self,
+                 borrowed_expected,
+                 within_ulps,
+                 format!("within <{}> ulp(s)", max_ulps));
+
+        self
+    }
+
+    /// Asserts that the subject is `NaN`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&(0.0f64 / 0.0)).is_nan();
+    /// ```
+    #[track_caller]
+    fn is_nan(&mut self) -> &mut Self {
+        let subject = *self.subject;
+
+        if !self.passes(subject.is_nan()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("<NaN>".to_string())
+                .with_negated_expected("float other than <NaN>".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is not `NaN`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1.0f64).is_not_nan();
+    /// ```
+    #[track_caller]
+    fn is_not_nan(&mut self) -> &mut Self {
+        self.toggle_negation().is_nan().toggle_negation()
+    }
+
+    /// Asserts that the subject is neither infinite nor `NaN`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1.0f64).is_finite();
+    /// ```
+    #[track_caller]
+    fn is_finite(&mut self) -> &mut Self {
         let subject = *self.subject;
-        let borrowed_expected = expected.borrow();
-        let borrowed_tolerance = tolerance.borrow();
 
-        let difference = (subject - *borrowed_expected).abs();
+        if !self.passes(subject.is_finite()) {
+            AssertionFailure::from_spec(self)
+                .with_expected("finite float".to_string())
+                .with_negated_expected("float which is not finite".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
 
-        if !subject.is_finite() || difference > borrowed_tolerance.abs() {
+    /// Asserts that the subject is positive or negative infinity.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&f64::INFINITY).is_infinite();
+    /// ```
+    #[track_caller]
+    fn is_infinite(&mut self) -> &mut Self {
+        let subject = *self.subject;
+
+        if !self.passes(subject.is_infinite()) {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("float close to <{:?}> (tolerance of <{:?}>)",
-                                       borrowed_expected,
-                                       borrowed_tolerance))
+                .with_expected("infinite float".to_string())
+                .with_negated_expected("float which is not infinite".to_string())
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
+    }
+}
+
+/// Fails unless the subject is finite and close to the expected value, showing how far apart
+/// the values are so that the tolerance can be tuned.
+#[cfg(feature = "num")]
+#[track_caller]
+fn is_close<'s, T: Float + Debug>(spec: &Spec<'s, T>, expected: T, close: bool, tolerance: String) {
+    let subject = *spec.subject;
+
+    if !spec.passes(subject.is_finite() && close) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("float close to <{:?}> ({})", expected, tolerance))
+            .with_negated_expected(format!("float not close to <{:?}> ({})", expected, tolerance))
+            .with_actual(describe_difference(subject, expected))
+            .fail();
+    }
+}
+
+/// Describes the float along with its difference, relative error and ULP distance from the
+/// expected value, or explains why it can never be close if it is `NaN` or infinite.
+#[cfg(feature = "num")]
+fn describe_difference<T: Float + Debug>(value: T, expected: T) -> String {
+    if value.is_nan() {
+        return format!("<{:?}> (NaN is not close to any value)", value);
+    } else if value.is_infinite() {
+        return format!("<{:?}> (infinite values are not close to any value)", value);
+    } else if expected.is_nan() {
+        return format!("<{:?}>", value);
+    }
+
+    let difference = (value - expected).abs();
+    let largest = value.abs().max(expected.abs());
+    let relative_error = if largest.is_zero() {
+        T::zero()
+    } else {
+        difference / largest
+    };
+
+    format!("<{:?}> (difference <{:?}>, relative error <{:?}>, <{}> ulp(s) apart)",
+            value,
+            difference,
+            relative_error,
+            ulp_distance(value, expected))
+}
+
+/// Counts the representable floats between the two values. `Float` is only implemented for
+/// `f32` and `f64`, so the size of the type tells us which representation to use.
+#[cfg(feature = "num")]
+fn ulp_distance<T: Float>(first: T, second: T) -> u64 {
+    if mem::size_of::<T>() == mem::size_of::<f32>() {
+        let ordered = |value: T| {
+            let value = value.to_f32().unwrap_or(f32::NAN);
+            let magnitude = (value.to_bits() & !(1 << 31)) as i32;
+            if value.is_sign_negative() { -magnitude } else { magnitude }
+        };
+
+        <u64 as From<u32>>::from(ordered(first).abs_diff(ordered(second)))
+    } else {
+        let ordered = |value: T| {
+            let value = value.to_f64().unwrap_or(f64::NAN);
+            let magnitude = (value.to_bits() & !(1 << 63)) as i64;
+            if value.is_sign_negative() { -magnitude } else { magnitude }
+        };
+
+        ordered(first).abs_diff(ordered(second))
     }
 }
 
@@ -148,6 +775,17 @@ mod tests {
         assert_that(&3).is_less_than(&2);
     }
 
+    #[test]
+    fn should_not_panic_if_value_is_not_less_than_expected_when_negated() {
+        assert_that(&3).not().is_less_than(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not less than <2>\n\t but was: <1>")]
+    fn should_panic_if_value_is_less_than_expected_when_negated() {
+        assert_that(&1).not().is_less_than(2);
+    }
+
     #[test]
     fn is_less_than_or_equal_to_should_allow_multiple_borrow_forms() {
         assert_that(&2).is_less_than_or_equal_to(2);
@@ -204,6 +842,77 @@ mod tests {
         assert_that(&2).is_greater_than_or_equal_to(&3);
     }
 
+    #[test]
+    fn should_not_panic_if_value_is_in_range() {
+        assert_that(&10).is_in_range(10..=20);
+        assert_that(&19).is_in_range(10..20);
+        assert_that(&-5).is_in_range(..0);
+        assert_that(&5).is_in_range(..=5);
+        assert_that(&5).is_in_range(5..);
+        assert_that(&5).is_in_range(..);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value in range <10..=20>\
+                   \n\t but was: <5> (below the lower bound <10>)")]
+    fn should_panic_if_value_is_below_range() {
+        assert_that(&5).is_in_range(10..=20);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value in range <10..20>\
+                   \n\t but was: <20> (not below the exclusive upper bound <20>)")]
+    fn should_panic_if_value_is_at_exclusive_end_of_range() {
+        assert_that(&20).is_in_range(10..20);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value in range <..=2.5>\
+                   \n\t but was: <3.0> (above the upper bound <2.5>)")]
+    fn should_panic_if_value_is_above_range() {
+        assert_that(&3.0).is_in_range(..=2.5);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_in_range() {
+        assert_that(&5).is_not_in_range(..0);
+        assert_that(&20).is_not_in_range(10..20);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not in range <..0>\n\t but was: <-3>")]
+    fn should_panic_if_value_is_in_range_when_not_expected() {
+        assert_that(&-3).is_not_in_range(..0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_between() {
+        assert_that(&10).is_between(10, 20, RangeEnds::Inclusive);
+        assert_that(&10).is_between(10, 20, RangeEnds::InclusiveLow);
+        assert_that(&20).is_between(10, 20, RangeEnds::InclusiveHigh);
+        assert_that(&0.5).is_between(0.0, 1.0, RangeEnds::Exclusive);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value in range <10..20> excluding <10>\
+                   \n\t but was: <10> (not above the exclusive lower bound <10>)")]
+    fn should_panic_if_value_is_not_between_exclusive_ends() {
+        assert_that(&10).is_between(10, 20, RangeEnds::Exclusive);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value in range <0.0..=1.0>\
+                   \n\t but was: <NaN> (not comparable with the lower bound <0.0>)")]
+    fn should_panic_if_value_is_not_comparable_with_range() {
+        assert_that(&f64::NAN).is_between(0.0, 1.0, RangeEnds::Inclusive);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not in range <1..=5>\n\t but was: <3>")]
+    fn should_panic_if_value_is_between_when_negated() {
+        assert_that(&3).not().is_between(1, 5, RangeEnds::Inclusive);
+    }
+
     #[test]
     fn is_close_to_should_allow_multiple_borrow_forms() {
         assert_that(&2.0f64).is_close_to(2.0f64, 0.01f64);
@@ -223,30 +932,188 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
-                   \n\t but was: <2>")]
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (tolerance of <0.01>)\
+                   \n\t but was: <2.0>")]
     fn should_panic_if_float_is_not_close_to() {
         assert_that(&2.0f64).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
-                   \n\t but was: <NaN>")]
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (tolerance of <0.01>)\
+                   \n\t but was: <NaN> (NaN is not close to any value)")]
     fn should_panic_if_float_is_nan() {
         assert_that(&Float::nan()).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (tolerance of <0.01>)\
                    \n\t but was: <inf>")]
     fn should_panic_if_float_is_infinity() {
         assert_that(&Float::infinity()).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (tolerance of <0.01>)\
                    \n\t but was: <-inf>")]
     fn should_panic_if_float_is_negative_infinity() {
         assert_that(&Float::neg_infinity()).is_close_to(1.0f64, 0.01f64);
     }
+
+    #[test]
+    fn should_not_panic_if_value_has_expected_sign() {
+        assert_that(&5).is_positive();
+        assert_that(&-0.5f64).is_negative();
+        assert_that(&0).not().is_positive();
+        assert_that(&-0.0f64).not().is_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: positive value\n\t but was: <0.0>")]
+    fn should_panic_if_zero_float_is_expected_to_be_positive() {
+        assert_that(&0.0f64).is_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: negative value\n\t but was: <NaN>")]
+    fn should_panic_if_nan_is_expected_to_be_negative() {
+        assert_that(&-f64::nan()).is_negative();
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_zero() {
+        assert_that(&0u8).is_zero();
+        assert_that(&-0.0f32).is_zero();
+        assert_that(&3).is_not_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not equal to zero\n\t but was: <0>")]
+    fn should_panic_if_value_is_zero_when_expected_not_to_be() {
+        assert_that(&0).is_not_zero();
+    }
+
+    #[test]
+    fn should_not_panic_if_value_has_expected_parity() {
+        assert_that(&4).is_even();
+        assert_that(&-3).is_odd();
+        assert_that(&0u64).is_even();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: odd value\n\t but was: <4>")]
+    fn should_panic_if_value_is_not_odd() {
+        assert_that(&4).is_odd();
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_divisible_by() {
+        assert_that(&12).is_divisible_by(3);
+        assert_that(&-12).is_divisible_by(4);
+        assert_that(&0).is_divisible_by(0);
+        assert_that(&12).not().is_divisible_by(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value divisible by <3>\
+                   \n\t but was: <10> (remainder <1>)")]
+    fn should_panic_if_value_is_not_divisible_by() {
+        assert_that(&10).is_divisible_by(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value divisible by <0>\n\t but was: <10>")]
+    fn should_panic_if_non_zero_value_is_divisible_by_zero() {
+        assert_that(&10).is_divisible_by(0);
+    }
+
+    #[test]
+    fn should_not_panic_if_float_has_expected_class() {
+        assert_that(&f64::nan()).is_nan();
+        assert_that(&1.0f64).is_not_nan().is_finite();
+        assert_that(&f32::neg_infinity()).is_infinite();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <NaN>\n\t but was: <1.5>")]
+    fn should_panic_if_float_is_not_nan() {
+        assert_that(&1.5f64).is_nan();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: float other than <NaN>\n\t but was: <NaN>")]
+    fn should_panic_if_float_is_nan_when_expected_not_to_be() {
+        assert_that(&f64::nan()).is_not_nan();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: finite float\n\t but was: <inf>")]
+    fn should_panic_if_float_is_not_finite() {
+        assert_that(&f64::infinity()).is_finite();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: infinite float\n\t but was: <NaN>")]
+    fn should_panic_if_float_is_not_infinite() {
+        assert_that(&f32::nan()).is_infinite();
+    }
+
+    #[test]
+    fn should_not_panic_if_float_is_close_to_relative() {
+        assert_that(&1e9f64).is_close_to_relative(1.000001e9f64, 1e-6f64);
+        assert_that(&1e-9f64).is_close_to_relative(1.000001e-9f64, 1e-6f64);
+        assert_that(&1e-9f64).not().is_close_to_relative(1.1e-9f64, 1e-6f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (relative tolerance of <0.1>)\
+                   \n\t but was: <2.0> (difference <1.0>, relative error <0.5>, \
+                   <4503599627370496> ulp(s) apart)")]
+    fn should_panic_if_float_is_not_close_to_relative() {
+        assert_that(&2.0f64).is_close_to_relative(1.0f64, 0.1f64);
+    }
+
+    #[test]
+    fn should_not_panic_if_float_is_close_to_with_tolerance() {
+        let tolerance = Tolerance::new().relative(1e-6).absolute(1e-12);
+
+        assert_that(&1e-13f64).is_close_to_with(0.0, tolerance);
+        assert_that(&1e9f64).is_close_to_with(1e9 + 100.0, tolerance);
+        assert_that(&1.0f32).not().is_close_to_with(1.0000001f32, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: float close to <0.0> \
+                   (relative tolerance of <1e-9>, absolute tolerance of <0.0>)\
+                   \n\t but was: <1e-9> (difference <1e-9>, relative error <1.0>, ")]
+    fn should_panic_if_float_is_compared_to_zero_without_absolute_tolerance() {
+        assert_that(&1e-9f64).is_close_to_with(0.0, Tolerance::new());
+    }
+
+    #[test]
+    fn should_not_panic_if_float_is_within_ulps() {
+        assert_that(&(0.1f64 + 0.2f64)).is_within_ulps(0.3f64, 1);
+        assert_that(&1.0f32).is_within_ulps(1.0000001f32, 1);
+        assert_that(&-0.0f64).is_within_ulps(0.0f64, 0);
+        assert_that(&f64::min_positive_value()).not().is_within_ulps(-f64::min_positive_value(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: float close to <0.3> (within <0> ulp(s))\
+                   \n\t but was: <0.30000000000000004> (difference <5.551115123125783e-17>")]
+    fn should_panic_if_float_is_not_within_ulps() {
+        assert_that(&(0.1f64 + 0.2f64)).is_within_ulps(0.3f64, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = ", <2> ulp(s) apart)")]
+    fn should_count_ulps_for_f32() {
+        assert_that(&1.0f32).is_within_ulps(1.0000002f32, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: float close to <1.0> (within <4> ulp(s))\
+                   \n\t but was: <NaN> (NaN is not close to any value)")]
+    fn should_panic_if_nan_is_within_ulps() {
+        assert_that(&f64::nan()).is_within_ulps(1.0, 4);
+    }
 }
@@ -1,4 +1,4 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec, Subject};
 
 use std::borrow::Borrow;
 use std::cmp::PartialEq;
@@ -8,13 +8,13 @@ pub trait OptionAssertions<'r, T>
     where T: Debug
 {
     fn is_some(&mut self) -> Spec<'r, T>;
-    fn is_none(&mut self);
+    fn is_none(&mut self) -> &mut Self;
 }
 
 pub trait ContainingOptionAssertions<T>
     where T: Debug + PartialEq
 {
-    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E);
+    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
 }
 
 impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
@@ -26,18 +26,22 @@ impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Some(1)).contains_value(&1);
     /// ```
-    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) {
+    #[track_caller]
+    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
         let borrowed_expected_value = expected_value.borrow();
 
-        match *self.subject {
+        if self.passes(self.subject.as_ref() == Some(borrowed_expected_value)) {
+            return self;
+        }
+
+        match *self.projected_subject("is_some") {
             Some(ref val) => {
-                if !val.eq(borrowed_expected_value) {
-                    AssertionFailure::from_spec(self)
-                        .with_expected(format!("option to contain <{:?}>",
-                                               borrowed_expected_value))
-                        .with_actual(format!("<{:?}>", val))
-                        .fail();
-                }
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("option to contain <{:?}>", borrowed_expected_value))
+                    .with_negated_expected(format!("option to not contain <{:?}>",
+                                                   borrowed_expected_value))
+                    .with_actual(format!("<{:?}>", val))
+                    .fail();
             }
             None => {
                 AssertionFailure::from_spec(self)
@@ -46,6 +50,8 @@ impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
                     .fail();
             }
         };
+
+        self
     }
 }
 
@@ -59,23 +65,25 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Some(1)).is_some();
     /// ```
+    #[track_caller]
     fn is_some(&mut self) -> Spec<'s, T> {
-        match *self.subject {
+        self.ensure_not_negated("is_some");
+
+        match *self.projected_subject("is_some") {
             Some(ref val) => {
                 Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
+                    subject: Subject::Borrowed(val),
+                    subject_name: self.subject_name.clone(),
                     location: self.location.clone(),
                     description: self.description,
+                    negated: false,
                 }
             }
             None => {
                 AssertionFailure::from_spec(self)
                     .with_expected(format!("option[some]"))
                     .with_actual(format!("option[none]"))
-                    .fail();
-
-                unreachable!();
+                    .fail_and_stop();
             }
         }
     }
@@ -85,9 +93,20 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Option::None::<String>).is_none();
     /// ```
-    fn is_none(&mut self) {
+    #[track_caller]
+    fn is_none(&mut self) -> &mut Self {
+        if self.passes(self.subject.is_none()) {
+            return self;
+        }
+
         match *self.subject {
-            None => (),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected("option[none]".to_string())
+                    .with_negated_expected("option[some]".to_string())
+                    .with_actual("option[none]".to_string())
+                    .fail();
+            }
             Some(ref val) => {
                 AssertionFailure::from_spec(self)
                     .with_expected(format!("option[none]"))
@@ -95,6 +114,8 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
                     .fail();
             }
         }
+
+        self
     }
 }
 
@@ -122,6 +143,14 @@ mod tests {
         assert_that(&option).is_some().is_equal_to(&"Hello");
     }
 
+    #[test]
+    fn should_be_able_to_keep_unwrapped_spec_of_borrowed_option() {
+        let option = Some("Hello");
+        let mut value = assert_that(&option).is_some();
+
+        value.is_equal_to("Hello");
+    }
+
     #[test]
     fn contains_value_should_allow_multiple_borrow_types() {
         let option = Some("Hello");
@@ -150,6 +179,19 @@ mod tests {
         assert_that(&option).contains_value(&"Hello");
     }
 
+    #[test]
+    fn should_not_panic_if_option_does_not_contain_value_when_negated() {
+        let option: Option<&str> = None;
+        assert_that(&option).not().contains_value("Hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: option[some]\n\t but was: option[none]")]
+    fn should_panic_if_option_is_none_when_negated() {
+        let option: Option<&str> = None;
+        assert_that(&option).not().is_none();
+    }
+
     #[test]
     fn should_not_panic_if_option_is_empty() {
         let option: Option<&str> = None;
@@ -163,4 +205,10 @@ mod tests {
         assert_that(&option).is_none();
     }
 
+    #[test]
+    fn should_allow_assertions_to_be_chained_after_unwrapping_option() {
+        let option = Some(5);
+        assert_that(&option).is_some().is_greater_than(1).is_less_than(10);
+    }
+
 }
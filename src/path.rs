@@ -4,11 +4,11 @@ use std::borrow::Borrow;
 use std::path::{Path, PathBuf};
 
 pub trait PathAssertions {
-    fn exists(&mut self);
-    fn does_not_exist(&mut self);
-    fn is_a_file(&mut self);
-    fn is_a_directory(&mut self);
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E);
+    fn exists(&mut self) -> &mut Self;
+    fn does_not_exist(&mut self) -> &mut Self;
+    fn is_a_file(&mut self) -> &mut Self;
+    fn is_a_directory(&mut self) -> &mut Self;
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self;
 }
 
 
@@ -18,8 +18,11 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).exists();
     /// ```
-    fn exists(&mut self) {
-        exists(self.subject, self)
+    #[track_caller]
+    fn exists(&mut self) -> &mut Self {
+        exists(&self.subject, self);
+
+        self
     }
 
     /// Asserts that the subject `Path` does not refer to an existing location.
@@ -27,8 +30,11 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).does_not_exist();
     /// ```
-    fn does_not_exist(&mut self) {
-        does_not_exist(self.subject, self)
+    #[track_caller]
+    fn does_not_exist(&mut self) -> &mut Self {
+        does_not_exist(&self.subject, self);
+
+        self
     }
 
     /// Asserts that the subject `Path` refers to an existing file.
@@ -36,8 +42,11 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).is_a_file();
     /// ```
-    fn is_a_file(&mut self) {
-        is_a_file(self.subject, self)
+    #[track_caller]
+    fn is_a_file(&mut self) -> &mut Self {
+        is_a_file(&self.subject, self);
+
+        self
     }
 
     /// Asserts that the subject `Path` refers to an existing directory.
@@ -45,8 +54,11 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/dir/")).is_a_directory();
     /// ```
-    fn is_a_directory(&mut self) {
-        is_a_directory(self.subject, self)
+    #[track_caller]
+    fn is_a_directory(&mut self) -> &mut Self {
+        is_a_directory(&self.subject, self);
+
+        self
     }
 
     /// Asserts that the subject `Path` has the expected file name.
@@ -54,8 +66,11 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).has_file_name(&"file");
     /// ```
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
-        has_file_name(self.subject, expected_file_name.borrow(), self)
+    #[track_caller]
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self {
+        has_file_name(&self.subject, expected_file_name.borrow(), self);
+
+        self
     }
 }
 
@@ -65,8 +80,11 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).exists();
     /// ```
-    fn exists(&mut self) {
-        exists(self.subject.as_path(), self)
+    #[track_caller]
+    fn exists(&mut self) -> &mut Self {
+        exists(self.subject.as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` does not refer to an existing location.
@@ -74,8 +92,11 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).does_not_exist();
     /// ```
-    fn does_not_exist(&mut self) {
-        does_not_exist(self.subject.as_path(), self)
+    #[track_caller]
+    fn does_not_exist(&mut self) -> &mut Self {
+        does_not_exist(self.subject.as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` refers to an existing file.
@@ -83,8 +104,11 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).is_a_file();
     /// ```
-    fn is_a_file(&mut self) {
-        is_a_file(self.subject.as_path(), self)
+    #[track_caller]
+    fn is_a_file(&mut self) -> &mut Self {
+        is_a_file(self.subject.as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` refers to an existing directory.
@@ -92,8 +116,11 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/dir/")).is_a_directory();
     /// ```
-    fn is_a_directory(&mut self) {
-        is_a_directory(self.subject.as_path(), self)
+    #[track_caller]
+    fn is_a_directory(&mut self) -> &mut Self {
+        is_a_directory(self.subject.as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` has the expected file name.
@@ -101,48 +128,79 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).has_file_name(&"file");
     /// ```
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
-        has_file_name(self.subject.as_path(), expected_file_name.borrow(), self)
+    #[track_caller]
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self {
+        has_file_name(self.subject.as_path(), expected_file_name.borrow(), self);
+
+        self
     }
 }
 
+#[track_caller]
 fn exists<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
-    if !subject.exists() {
+    if !spec.passes(subject.exists()) {
         AssertionFailure::from_spec(spec)
             .with_expected(format!("Path of <{:?}> to exist", subject))
-            .with_actual(format!("a non-existent Path"))
+            .with_negated_expected(format!("Path of <{:?}> to not exist", subject))
+            .with_actual(describe_existence(subject))
             .fail();
     }
 }
 
+#[track_caller]
 fn does_not_exist<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
-    if subject.exists() {
+    if !spec.passes(!subject.exists()) {
         AssertionFailure::from_spec(spec)
             .with_expected(format!("Path of <{:?}> to not exist", subject))
-            .with_actual(format!("a resolvable Path"))
+            .with_negated_expected(format!("Path of <{:?}> to exist", subject))
+            .with_actual(describe_existence(subject))
             .fail();
     }
 }
 
+#[track_caller]
 fn is_a_file<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
-    if !subject.is_file() {
+    if !spec.passes(subject.is_file()) {
+        let actual = if subject.is_file() {
+            "a resolvable file"
+        } else {
+            "not a resolvable file"
+        };
+
         AssertionFailure::from_spec(spec)
             .with_expected(format!("Path of <{:?}> to be a file", subject))
-            .with_actual(format!("not a resolvable file"))
+            .with_negated_expected(format!("Path of <{:?}> to not be a file", subject))
+            .with_actual(actual.to_string())
             .fail();
     }
 }
 
+#[track_caller]
 fn is_a_directory<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
-    if !subject.is_dir() {
+    if !spec.passes(subject.is_dir()) {
+        let actual = if subject.is_dir() {
+            "a resolvable directory"
+        } else {
+            "not a resolvable directory"
+        };
+
         AssertionFailure::from_spec(spec)
             .with_expected(format!("Path of <{:?}> to be a directory", subject))
-            .with_actual(format!("not a resolvable directory"))
+            .with_negated_expected(format!("Path of <{:?}> to not be a directory", subject))
+            .with_actual(actual.to_string())
             .fail();
     }
 }
 
+fn describe_existence(subject: &Path) -> String {
+    if subject.exists() {
+        "a resolvable Path".to_string()
+    } else {
+        "a non-existent Path".to_string()
+    }
+}
 
+#[track_caller]
 fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
                                              expected_file_name: &str,
                                              spec: &'s S) {
@@ -151,29 +209,39 @@ fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
             match os_string.to_str() {
                 Some(val) => val,
                 None => {
+                    if spec.is_negated() {
+                        return;
+                    }
+
                     fail_from_file_name(spec,
                                         expected_file_name,
                                         format!("an invalid UTF-8 file name"));
-                    unreachable!();
+                    return;
                 }
             }
         }
         None => {
+            if spec.is_negated() {
+                return;
+            }
+
             fail_from_file_name(spec,
                                 expected_file_name,
                                 format!("a non-resolvable path <{:?}>", subject));
-            unreachable!();
+            return;
         }
     };
 
-    if !subject_file_name.eq(expected_file_name) {
+    if !spec.passes(subject_file_name.eq(expected_file_name)) {
         fail_from_file_name(spec, expected_file_name, format!("<{}>", subject_file_name));
     }
 }
 
+#[track_caller]
 fn fail_from_file_name<'s, S: DescriptiveSpec<'s>>(spec: &'s S, expected: &str, actual: String) {
     AssertionFailure::from_spec(spec)
         .with_expected(build_file_name_message(expected))
+        .with_negated_expected(format!("Path with a file name other than <{}>", expected))
         .with_actual(actual)
         .fail();
 }
@@ -204,6 +272,21 @@ mod tests {
         assert_that(&Path::new(&failing_path)).exists();
     }
 
+    #[test]
+    pub fn should_not_panic_if_path_assertions_fail_when_negated() {
+        let manifest = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&Path::new(&manifest)).not().does_not_exist();
+        assert_that(&Path::new(MANIFEST_PATH)).not().is_a_file();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path with a file name other than <Cargo.toml>\
+                   \n\t but was: <Cargo.toml>")]
+    pub fn should_panic_if_path_has_file_name_when_negated() {
+        let manifest = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&Path::new(&manifest)).not().has_file_name("Cargo.toml");
+    }
+
     #[test]
     pub fn should_not_panic_if_path_represents_a_directory() {
         assert_that(&Path::new(MANIFEST_PATH)).is_a_directory();
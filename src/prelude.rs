@@ -1,14 +1,27 @@
-pub use super::{asserting, assert_that};
+pub use super::{asserting, assert_that, assert_that_owned, check_that};
 pub use super::boolean::BooleanAssertions;
+pub use super::closure::PanicAssertions;
+pub use super::eventually::{assert_that_eventually, eventually};
+pub use super::formatting::FormattingAssertions;
 pub use super::hashmap::HashMapAssertions;
 pub use super::iter::{ContainingIntoIterAssertions, ContainingIteratorAssertions,
                       MappingIterAssertions};
-pub use super::numeric::OrderedAssertions;
+pub use super::matchers::Matcher;
+pub use super::numeric::{OrderedAssertions, RangeEnds};
 pub use super::option::{OptionAssertions, ContainingOptionAssertions};
 pub use super::path::PathAssertions;
 pub use super::result::{ContainingResultAssertions, ResultAssertions};
-pub use super::string::StrAssertions;
+pub use super::snapshot::SnapshotAssertions;
+pub use super::soft::assert_all;
+pub use super::string::{LineAssertions, LineOptions, ParsingAssertions, StrAssertions};
+pub use super::time::{DurationAssertions, TimeAssertions};
 pub use super::vec::VecAssertions;
 
 #[cfg(feature = "num")]
-pub use super::numeric::FloatAssertions;
+pub use super::numeric::{FloatAssertions, IntegerAssertions, SignedAssertions, Tolerance,
+                         ZeroAssertions};
+#[cfg(feature = "num")]
+pub use super::vec::{FloatMatrixAssertions, FloatVecAssertions};
+
+#[cfg(feature = "regex")]
+pub use super::string::RegexAssertions;
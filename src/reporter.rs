@@ -0,0 +1,430 @@
+use super::AssertionError;
+use super::colour::Theme;
+
+use std::cell::RefCell;
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread;
+
+/// The environment variable naming the file that `JsonLinesReporter::from_env` appends to.
+pub const JSON_REPORT_VARIABLE: &str = "SPECTRAL_JSON_REPORT";
+
+/// The environment variable naming the file that `JUnitReporter::from_env` appends to.
+pub const JUNIT_REPORT_VARIABLE: &str = "SPECTRAL_JUNIT_REPORT";
+
+static GLOBAL_REPORTER: RwLock<Option<Arc<dyn FailureReporter>>> = RwLock::new(None);
+
+thread_local! {
+    static THREAD_REPORTER: RefCell<Option<Arc<dyn FailureReporter>>> =
+        const { RefCell::new(None) };
+}
+
+/// Receives assertion failures, in place of the default behaviour of panicking.
+///
+/// Failures collected by an `assert_all` block are passed to the reporter together at the end of
+/// the block, as a single failure with the numbered report as its message. Failures collected by a
+/// `check_that` call are returned rather than reported.
+///
+/// If the reporter returns normally, the assertion carries on as if it had passed, except for
+/// assertions which return a new `Spec` (such as `is_some`), which still unwind.
+pub trait FailureReporter: Send + Sync {
+    fn report(&self, failure: &AssertionError);
+}
+
+/// Sets the reporter used for assertion failures on every thread, for the rest of the process.
+///
+/// A reporter set with `with_failure_reporter` takes priority over this one.
+///
+/// ```rust,ignore
+/// if let Some(reporter) = JsonLinesReporter::from_env() {
+///     set_failure_reporter(reporter);
+/// }
+/// ```
+pub fn set_failure_reporter<R>(reporter: R)
+    where R: FailureReporter + 'static
+{
+    *GLOBAL_REPORTER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(reporter));
+}
+
+/// Restores the default behaviour of panicking on assertion failures, for every thread.
+pub fn reset_failure_reporter() {
+    *GLOBAL_REPORTER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Uses the reporter for assertion failures made on the current thread while the block runs.
+///
+/// ```rust,ignore
+/// let reporter = CollectingReporter::new();
+///
+/// with_failure_reporter(reporter.clone(), || {
+///     assert_that(&1).is_equal_to(2);
+/// });
+///
+/// assert_that(&reporter.failures()).has_length(1);
+/// ```
+pub fn with_failure_reporter<R, F, T>(reporter: R, block: F) -> T
+    where R: FailureReporter + 'static,
+          F: FnOnce() -> T
+{
+    let previous = THREAD_REPORTER.with(|current| current.replace(Some(Arc::new(reporter))));
+    let _restore = RestoreReporter { previous: Some(previous) };
+
+    block()
+}
+
+/// Puts back the previous thread reporter, even if the block passed to `with_failure_reporter`
+/// panics.
+struct RestoreReporter {
+    previous: Option<Option<Arc<dyn FailureReporter>>>,
+}
+
+impl Drop for RestoreReporter {
+    fn drop(&mut self) {
+        let previous = self.previous.take().unwrap_or_default();
+        THREAD_REPORTER.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Returns the reporter for the current thread, if one has been set.
+pub(crate) fn current_reporter() -> Option<Arc<dyn FailureReporter>> {
+    THREAD_REPORTER.with(|current| current.borrow().clone()).or_else(|| {
+        GLOBAL_REPORTER.read().unwrap_or_else(PoisonError::into_inner).clone()
+    })
+}
+
+/// Panics with the failure message. This is the default behaviour.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PanicReporter;
+
+impl FailureReporter for PanicReporter {
+    fn report(&self, failure: &AssertionError) {
+        panic!("{}", failure.build_message(&Theme::current()));
+    }
+}
+
+/// Keeps every failure rather than panicking, which is useful for testing your own assertions.
+///
+/// Clones share the same failures, so a clone can be passed to `with_failure_reporter` and the
+/// original inspected afterwards.
+#[derive(Clone, Debug, Default)]
+pub struct CollectingReporter {
+    failures: Arc<Mutex<Vec<AssertionError>>>,
+}
+
+impl CollectingReporter {
+    pub fn new() -> CollectingReporter {
+        CollectingReporter::default()
+    }
+
+    /// Returns the failures reported so far.
+    pub fn failures(&self) -> Vec<AssertionError> {
+        self.failures.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+impl FailureReporter for CollectingReporter {
+    fn report(&self, failure: &AssertionError) {
+        self.failures.lock().unwrap_or_else(PoisonError::into_inner).push(failure.clone());
+    }
+}
+
+/// Appends each failure to a file as a line of JSON, and then panics as usual.
+#[derive(Clone, Debug)]
+pub struct JsonLinesReporter {
+    path: PathBuf,
+}
+
+impl JsonLinesReporter {
+    pub fn new<P: Into<PathBuf>>(path: P) -> JsonLinesReporter {
+        JsonLinesReporter { path: path.into() }
+    }
+
+    /// Creates a reporter for the file named by the `SPECTRAL_JSON_REPORT` environment variable,
+    /// if it is set.
+    pub fn from_env() -> Option<JsonLinesReporter> {
+        env::var_os(JSON_REPORT_VARIABLE).map(JsonLinesReporter::new)
+    }
+}
+
+impl FailureReporter for JsonLinesReporter {
+    fn report(&self, failure: &AssertionError) {
+        append_or_warn(&self.path, &format!("{}\n", build_json_line(failure)));
+        PanicReporter.report(failure);
+    }
+}
+
+/// Appends each failure to a file as a JUnit-style `<testcase>` XML fragment, and then panics
+/// as usual.
+#[derive(Clone, Debug)]
+pub struct JUnitReporter {
+    path: PathBuf,
+}
+
+impl JUnitReporter {
+    pub fn new<P: Into<PathBuf>>(path: P) -> JUnitReporter {
+        JUnitReporter { path: path.into() }
+    }
+
+    /// Creates a reporter for the file named by the `SPECTRAL_JUNIT_REPORT` environment
+    /// variable, if it is set.
+    pub fn from_env() -> Option<JUnitReporter> {
+        env::var_os(JUNIT_REPORT_VARIABLE).map(JUnitReporter::new)
+    }
+}
+
+impl FailureReporter for JUnitReporter {
+    fn report(&self, failure: &AssertionError) {
+        append_or_warn(&self.path, &format!("{}\n", build_junit_fragment(failure)));
+        PanicReporter.report(failure);
+    }
+}
+
+fn append_or_warn(path: &Path, content: &str) {
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()));
+
+    if let Err(error) = result {
+        let _ = writeln!(io::stderr(),
+                         "spectral: unable to write failure report to {}: {}",
+                         path.display(),
+                         error);
+    }
+}
+
+/// The name of the running test, which the test harness uses as the name of its thread.
+fn test_name() -> String {
+    thread::current().name().unwrap_or("unknown").to_string()
+}
+
+fn build_json_line(failure: &AssertionError) -> String {
+    let fields = [("test", Some(test_name())),
+                  ("description", failure.description.clone()),
+                  ("subject_name", failure.subject_name.clone()),
+                  ("location", failure.location.clone()),
+                  ("expected", failure.expected.clone()),
+                  ("actual", failure.actual.clone()),
+                  ("message", failure.message.clone()),
+                  ("diff", failure.diff.clone())];
+
+    let members: Vec<String> = fields.iter()
+        .map(|&(name, ref value)| {
+            let value = match *value {
+                Some(ref value) => escape_json(value),
+                None => "null".to_string(),
+            };
+            format!("\"{}\":{}", name, value)
+        })
+        .collect();
+
+    format!("{{{}}}", members.join(","))
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", character as u32);
+            }
+            character => escaped.push(character),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn build_junit_fragment(failure: &AssertionError) -> String {
+    let test_name = test_name();
+    let (class_name, name) = match test_name.rfind("::") {
+        Some(index) => (&test_name[..index], &test_name[index + 2..]),
+        None => ("", test_name.as_str()),
+    };
+
+    let summary = match failure.message {
+        Some(ref message) => message.clone(),
+        None => {
+            format!("expected {} but was {}",
+                    failure.expected.as_ref().map_or("", |value| value.as_str()),
+                    failure.actual.as_ref().map_or("", |value| value.as_str()))
+        }
+    };
+
+    format!("<testcase classname=\"{}\" name=\"{}\"><failure type=\"AssertionFailure\" \
+             message=\"{}\">{}</failure></testcase>",
+            escape_xml(class_name),
+            escape_xml(name),
+            escape_xml(&summary),
+            escape_xml(&failure.to_string()))
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::*;
+
+    use std::fs;
+    use std::process;
+
+    #[test]
+    fn should_pass_failures_to_reporter_instead_of_panicking() {
+        let reporter = CollectingReporter::new();
+
+        with_failure_reporter(reporter.clone(), || {
+            assert_that(&1).is_equal_to(2);
+            assert_that(&"Hello").named("greeting").starts_with("Hi");
+        });
+
+        let failures = reporter.failures();
+        assert_that(&failures).has_length(2);
+        assert_that(&failures[0].expected).contains_value("<2>".to_string());
+        assert_that(&failures[1].subject_name).contains_value("greeting".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_restore_previous_reporter_after_block() {
+        with_failure_reporter(CollectingReporter::new(), || {});
+
+        assert_that(&1).is_equal_to(2);
+    }
+
+    #[test]
+    fn should_collect_soft_assertions_before_reporting() {
+        let reporter = CollectingReporter::new();
+
+        with_failure_reporter(reporter.clone(), || {
+            let result = check_that(&1, |spec| {
+                spec.is_equal_to(2);
+            });
+            assert_that(&result).is_err();
+        });
+
+        assert_that(&reporter.failures()).is_empty();
+    }
+
+    #[test]
+    fn should_report_failures_of_soft_assertions_at_end_of_block() {
+        let reporter = CollectingReporter::new();
+
+        with_failure_reporter(reporter.clone(), || {
+            assert_all(|s| {
+                s.that(&1).is_equal_to(2);
+                s.that(&"Hello").starts_with("W");
+            });
+        });
+
+        let failures = reporter.failures();
+        assert_that(&failures).has_length(1);
+        assert_that(&failures[0].message).is_some().starts_with("2 soft assertion(s) failed:");
+        assert_that(&failures[0].location).is_some().contains("src/reporter.rs:");
+    }
+
+    #[test]
+    fn should_build_json_line_for_failure() {
+        let reporter = CollectingReporter::new();
+
+        with_failure_reporter(reporter.clone(), || {
+            asserting("quoted \"value\"").that(&1).is_equal_to(2);
+        });
+
+        let line = build_json_line(&reporter.failures()[0]);
+        assert_that(&line).starts_with("{\"test\":\"reporter::tests::\
+                                         should_build_json_line_for_failure\",\
+                                         \"description\":\"quoted \\\"value\\\"\",\
+                                         \"subject_name\":null,\"location\":\"src/reporter.rs:");
+        assert_that(&line).ends_with("\"expected\":\"<2>\",\"actual\":\"<1>\",\"message\":null,\
+                                      \"diff\":null}");
+    }
+
+    #[test]
+    fn should_build_junit_fragment_for_failure() {
+        let reporter = CollectingReporter::new();
+
+        with_failure_reporter(reporter.clone(), || {
+            assert_that(&"<a>").is_equal_to("<b>");
+        });
+
+        let fragment = build_junit_fragment(&reporter.failures()[0]);
+        assert_that(&fragment).starts_with("<testcase classname=\"reporter::tests\" \
+                                            name=\"should_build_junit_fragment_for_failure\">\
+                                            <failure type=\"AssertionFailure\" message=\"expected \
+                                            &lt;&quot;&lt;b&gt;&quot;&gt; but was \
+                                            &lt;&quot;&lt;a&gt;&quot;&gt;\">");
+        assert_that(&fragment).ends_with("</failure></testcase>");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: option[some]\n\t but was: option[none]")]
+    fn should_panic_with_message_if_assertion_cannot_continue_after_reporting() {
+        let reporter = CollectingReporter::new();
+
+        let outcome = ::std::panic::catch_unwind(|| {
+            with_failure_reporter(reporter.clone(), || {
+                assert_that(&None::<u32>).is_some();
+            });
+        });
+
+        assert_that(&reporter.failures()).has_length(1);
+
+        if let Err(payload) = outcome {
+            ::std::panic::resume_unwind(payload);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_append_json_line_and_panic() {
+        let path = env::temp_dir()
+            .join(format!("spectral_should_append_json_line_and_panic_{}.jsonl", process::id()));
+        let _ = fs::remove_file(&path);
+
+        with_failure_reporter(JsonLinesReporter::new(&path), || {
+            let outcome = ::std::panic::catch_unwind(|| {
+                assert_that(&1).is_equal_to(2);
+            });
+
+            let written = fs::read_to_string(&path).unwrap();
+            let _ = fs::remove_file(&path);
+
+            assert_that(&written.lines().count()).is_equal_to(1);
+            assert_that(&written).ends_with("\"diff\":null}\n");
+
+            if let Err(payload) = outcome {
+                ::std::panic::resume_unwind(payload);
+            }
+        });
+    }
+}
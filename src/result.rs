@@ -1,4 +1,4 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec, Subject};
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
@@ -15,8 +15,10 @@ pub trait ContainingResultAssertions<T, E>
     where T: Debug,
           E: Debug
 {
-    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) where T: PartialEq;
-    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) where E: PartialEq;
+    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) -> &mut Self
+        where T: PartialEq;
+    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) -> &mut Self
+        where E: PartialEq;
 }
 
 impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
@@ -29,19 +31,30 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Ok::<usize, usize>(1)).is_ok_containing(&1);
     /// ```
-    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V)
+    #[track_caller]
+    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) -> &mut Self
         where T: PartialEq
     {
         let borrowed_expected_value = expected_value.borrow();
 
+        let contains_value = match *self.subject {
+            Ok(ref val) => val.eq(borrowed_expected_value),
+            _ => false,
+        };
+
+        if self.passes(contains_value) {
+            return self;
+        }
+
         match *self.subject {
             Ok(ref val) => {
-                if !val.eq(borrowed_expected_value) {
-                    AssertionFailure::from_spec(self)
-                        .with_expected(build_detail_message("ok", borrowed_expected_value))
-                        .with_actual(build_detail_message("ok", val))
-                        .fail();
-                }
+                AssertionFailure::from_spec(self)
+                    .with_expected(build_detail_message("ok", borrowed_expected_value))
+                    .with_negated_expected(format!("not {}",
+                                                   build_detail_message("ok",
+                                                                        borrowed_expected_value)))
+                    .with_actual(build_detail_message("ok", val))
+                    .fail();
             }
             Err(ref val) => {
                 AssertionFailure::from_spec(self)
@@ -50,6 +63,8 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
                     .fail();
             }
         }
+
+        self
     }
 
     /// Asserts that the subject is an `Err` Result containing the expected value.
@@ -58,19 +73,30 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Err::<usize, usize>(1)).is_err_containing(&1);
     /// ```
-    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V)
+    #[track_caller]
+    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) -> &mut Self
         where E: PartialEq
     {
         let borrowed_expected_value = expected_value.borrow();
 
+        let contains_value = match *self.subject {
+            Err(ref val) => val.eq(borrowed_expected_value),
+            _ => false,
+        };
+
+        if self.passes(contains_value) {
+            return self;
+        }
+
         match *self.subject {
             Err(ref val) => {
-                if !val.eq(borrowed_expected_value) {
-                    AssertionFailure::from_spec(self)
-                        .with_expected(build_detail_message("err", borrowed_expected_value))
-                        .with_actual(build_detail_message("err", val))
-                        .fail();
-                }
+                AssertionFailure::from_spec(self)
+                    .with_expected(build_detail_message("err", borrowed_expected_value))
+                    .with_negated_expected(format!("not {}",
+                                                   build_detail_message("err",
+                                                                        borrowed_expected_value)))
+                    .with_actual(build_detail_message("err", val))
+                    .fail();
             }
             Ok(ref val) => {
                 AssertionFailure::from_spec(self)
@@ -79,10 +105,12 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
                     .fail();
             }
         }
+
+        self
     }
 }
 
-fn build_detail_message<T: Debug>(variant: &'static str, value: T) -> String {
+pub(crate) fn build_detail_message<T: Debug>(variant: &'static str, value: T) -> String {
     format!("Result[{}] containing <{:?}>", variant, value)
 }
 
@@ -97,23 +125,25 @@ impl<'s, T, E> ResultAssertions<'s, T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Ok::<usize, usize>(1)).is_ok();
     /// ```
+    #[track_caller]
     fn is_ok(&mut self) -> Spec<'s, T> {
-        match *self.subject {
+        self.ensure_not_negated("is_ok");
+
+        match *self.projected_subject("is_ok") {
             Ok(ref val) => {
                 Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
+                    subject: Subject::Borrowed(val),
+                    subject_name: self.subject_name.clone(),
                     location: self.location.clone(),
                     description: self.description,
+                    negated: false,
                 }
             }
             Err(ref err) => {
                 AssertionFailure::from_spec(self)
                     .with_expected(format!("result[ok]"))
                     .with_actual(format!("result[error]<{:?}>", err))
-                    .fail();
-
-                unreachable!();
+                    .fail_and_stop();
             }
         }
     }
@@ -125,23 +155,25 @@ impl<'s, T, E> ResultAssertions<'s, T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Err::<usize, usize>(1)).is_err();
     /// ```
+    #[track_caller]
     fn is_err(&mut self) -> Spec<'s, E> {
-        match *self.subject {
+        self.ensure_not_negated("is_err");
+
+        match *self.projected_subject("is_err") {
             Err(ref val) => {
                 Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
+                    subject: Subject::Borrowed(val),
+                    subject_name: self.subject_name.clone(),
                     location: self.location.clone(),
                     description: self.description,
+                    negated: false,
                 }
             }
             Ok(ref val) => {
                 AssertionFailure::from_spec(self)
                     .with_expected(format!("result[error]"))
                     .with_actual(format!("result[ok]<{:?}>", val))
-                    .fail();
-
-                unreachable!();
+                    .fail_and_stop();
             }
         }
     }
@@ -0,0 +1,243 @@
+use super::{AssertionFailure, Spec};
+
+use std::env;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::io;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+
+/// Set this environment variable to `1` to write (or refresh) snapshots rather than comparing
+/// against them.
+pub const UPDATE_SNAPSHOTS_VARIABLE: &str = "SPECTRAL_UPDATE_SNAPSHOTS";
+
+const SNAPSHOT_DIRECTORY: &str = "snapshots";
+
+pub trait SnapshotAssertions<S> {
+    fn matches_snapshot(&mut self, name: &str) -> &mut Self where S: Display;
+    fn matches_debug_snapshot(&mut self, name: &str) -> &mut Self where S: Debug;
+}
+
+impl<'s, S> SnapshotAssertions<S> for Spec<'s, S> {
+    /// Asserts that the `Display` output of the subject matches the snapshot with the given
+    /// name. The subject type must implement `Display`.
+    ///
+    /// Snapshots are stored in a `snapshots` directory next to the file containing the
+    /// assertion. Running with `SPECTRAL_UPDATE_SNAPSHOTS=1` writes (or refreshes) the snapshot
+    /// rather than comparing against it.
+    ///
+    /// ```rust,ignore
+    /// assert_that!(&render(&template)).matches_snapshot("welcome_email");
+    /// ```
+    #[track_caller]
+    fn matches_snapshot(&mut self, name: &str) -> &mut Self
+        where S: Display
+    {
+        let content = self.subject.to_string();
+        let path = snapshot_path(self, name);
+
+        check_snapshot(self, name, &path, &content, should_update_snapshots());
+        self
+    }
+
+    /// Asserts that the pretty-printed `Debug` output of the subject matches the snapshot with
+    /// the given name. The subject type must implement `Debug`.
+    ///
+    /// Snapshots are stored in the same way as for `matches_snapshot`.
+    ///
+    /// ```rust,ignore
+    /// assert_that!(&parse(source)).matches_debug_snapshot("parsed_module");
+    /// ```
+    #[track_caller]
+    fn matches_debug_snapshot(&mut self, name: &str) -> &mut Self
+        where S: Debug
+    {
+        let content = format!("{:#?}", self.subject);
+        let path = snapshot_path(self, name);
+
+        check_snapshot(self, name, &path, &content, should_update_snapshots());
+        self
+    }
+}
+
+#[track_caller]
+fn check_snapshot<S>(spec: &Spec<S>, name: &str, path: &Path, content: &str, update: bool) {
+    spec.ensure_not_negated("matches_snapshot");
+
+    if update {
+        if let Err(error) = write_snapshot(path, content) {
+            panic!("unable to write snapshot to {}: {}", path.display(), error);
+        }
+        return;
+    }
+
+    let stored = match fs::read_to_string(path) {
+        Ok(stored) => stored,
+        Err(_) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("snapshot <{}> at <{}>", name, path.display()))
+                .with_actual(format!("no snapshot (run with {}=1 to create it)",
+                                     UPDATE_SNAPSHOTS_VARIABLE))
+                .fail();
+            return;
+        }
+    };
+
+    if stored != content {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("value matching snapshot <{}> at <{}>", name, path.display()))
+            .with_actual("<value shown in diff>".to_string())
+            .with_diff(&stored, content)
+            .fail();
+    }
+}
+
+fn should_update_snapshots() -> bool {
+    env::var(UPDATE_SNAPSHOTS_VARIABLE).is_ok_and(|value| value == "1")
+}
+
+fn write_snapshot(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    fs::write(path, content)
+}
+
+/// Finds the snapshot file for the assertion, in a `snapshots` directory next to the source
+/// file containing it. The source file is taken from the location given by the macro form of
+/// `assert_that`, or otherwise the location of the caller.
+#[track_caller]
+fn snapshot_path<S>(spec: &Spec<S>, name: &str) -> PathBuf {
+    let source_file = match spec.location {
+        Some(ref location) => {
+            location.rsplit_once(':').map_or(location.as_str(), |(file, _)| file).to_string()
+        }
+        None => Location::caller().file().to_string(),
+    };
+
+    let source_file = resolve_source_file(Path::new(&source_file));
+    let file_stem = source_file.file_stem()
+        .map_or("snapshot".into(), |stem| stem.to_string_lossy());
+    let directory = source_file.parent().unwrap_or_else(|| Path::new(""));
+
+    directory.join(SNAPSHOT_DIRECTORY).join(format!("{}__{}.snap", file_stem, name))
+}
+
+/// Source file paths are relative to the workspace root, whereas tests run in the package
+/// directory, so this searches upwards from the current directory for the source file.
+fn resolve_source_file(source_file: &Path) -> PathBuf {
+    if source_file.is_absolute() {
+        return source_file.to_path_buf();
+    }
+
+    if let Ok(current_directory) = env::current_dir() {
+        for directory in current_directory.ancestors() {
+            let candidate = directory.join(source_file);
+            if candidate.exists() {
+                return candidate;
+            }
+        }
+    }
+
+    source_file.to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::check_snapshot;
+
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    /// A snapshot in a temporary directory unique to the test, which is removed when dropped.
+    struct TempSnapshot {
+        directory: PathBuf,
+        path: PathBuf,
+    }
+
+    impl TempSnapshot {
+        fn new(name: &str, content: Option<&str>) -> TempSnapshot {
+            let directory = env::temp_dir()
+                .join(format!("spectral_snapshots_{}_{}", process::id(), name));
+            let path = directory.join(format!("snapshot__{}.snap", name));
+
+            let _ = fs::remove_dir_all(&directory);
+            if let Some(content) = content {
+                fs::create_dir_all(&directory).unwrap();
+                fs::write(&path, content).unwrap();
+            }
+
+            TempSnapshot {
+                directory,
+                path,
+            }
+        }
+    }
+
+    impl Drop for TempSnapshot {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_value_matches_snapshot() {
+        assert_that!(&"Hello, world!\nGoodbye!\n").matches_snapshot("farewell");
+        assert_that(&"Hello, world!\nGoodbye!\n").matches_snapshot("farewell");
+    }
+
+    #[test]
+    fn should_not_panic_if_debug_output_matches_snapshot() {
+        let greetings = vec![("world", true), ("moon", false)];
+
+        assert_that(&greetings).matches_debug_snapshot("greeting");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value matching snapshot <farewell> at <")]
+    fn should_panic_if_value_does_not_match_snapshot() {
+        let snapshot = TempSnapshot::new("mismatched", Some("Hello, world!\nGoodbye!\n"));
+        let content = "Hello, world!\nSee you!\n";
+
+        check_snapshot(&assert_that(&content), "farewell", &snapshot.path, content, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "diff (- expected, + actual):\n\t  Hello, world!\n\t- Goodbye!\
+                               \n\t+ See you!")]
+    fn should_show_diff_against_snapshot() {
+        let snapshot = TempSnapshot::new("diffed", Some("Hello, world!\nGoodbye!\n"));
+        let content = "Hello, world!\nSee you!\n";
+
+        check_snapshot(&assert_that(&content), "farewell", &snapshot.path, content, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: snapshot <missing> at <")]
+    fn should_panic_if_snapshot_does_not_exist() {
+        let snapshot = TempSnapshot::new("missing", None);
+
+        check_snapshot(&assert_that(&"Hello"), "missing", &snapshot.path, "Hello", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "`matches_snapshot` cannot be negated with `not()`")]
+    fn should_panic_if_snapshot_assertion_is_negated() {
+        assert_that(&"Hello").not().matches_snapshot("farewell");
+    }
+
+    #[test]
+    fn should_write_snapshot_when_updating() {
+        let snapshot = TempSnapshot::new("written", None);
+
+        check_snapshot(&assert_that(&"Hello"), "written", &snapshot.path, "Hello", true);
+        check_snapshot(&assert_that(&"Hello"), "written", &snapshot.path, "Hello", false);
+
+        assert_that(&fs::read_to_string(&snapshot.path).unwrap()).is_equal_to("Hello".to_string());
+    }
+}
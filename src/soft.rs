@@ -0,0 +1,262 @@
+use super::{asserting, assert_that, reporter, AssertionError, Spec, SpecDescription};
+use super::colour::Theme;
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::thread;
+
+thread_local! {
+    static COLLECTED_FAILURES: RefCell<Vec<Vec<AssertionError>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Payload used to unwind out of an `assert_all` block when an assertion cannot carry on after
+/// failing (for example, `is_some` on a `None` has no value to return).
+struct StopAssertions;
+
+/// Collects assertion failures for the duration of an `assert_all` block.
+///
+/// This is passed to the closure given to `assert_all`, and is used in place of the
+/// `assert_that` and `asserting` functions.
+#[derive(Debug)]
+pub struct SoftAssertions {
+    _private: (),
+}
+
+impl SoftAssertions {
+    /// Wraps a subject in a `Spec`, collecting rather than panicking on failure.
+    pub fn that<'s, S>(&self, subject: &'s S) -> Spec<'s, S> {
+        assert_that(subject)
+    }
+
+    /// Describes an assertion, collecting rather than panicking on failure.
+    pub fn asserting<'r>(&self, description: &'r str) -> SpecDescription<'r> {
+        asserting(description)
+    }
+}
+
+/// Runs every assertion in the provided block, collecting the failures rather than stopping at
+/// the first one. If any assertions failed, this will panic once at the end of the block with a
+/// numbered report of every failure. If a `FailureReporter` has been set, the report is passed to
+/// it as a single failure instead.
+///
+/// Any assertion made on the current thread while the block is running is collected, including
+/// those made through `assert_that` directly. Assertions which return a new `Spec` (such as
+/// `is_some` or `contains_key`) cannot continue when they fail, so the rest of the block is
+/// skipped in that case.
+///
+/// ```rust,ignore
+/// assert_all(|s| {
+///     s.that(&response.status).is_equal_to(200);
+///     s.that(&response.body).contains(&"hello");
+/// });
+/// ```
+#[track_caller]
+pub fn assert_all<F>(block: F)
+    where F: FnOnce(&SoftAssertions)
+{
+    let soft = SoftAssertions { _private: () };
+    let (result, failures) = collect_failures(|| block(&soft));
+
+    let theme = Theme::current();
+    let mut messages: Vec<String> = failures.iter()
+        .map(|failure| failure.build_message(&theme))
+        .collect();
+
+    if let Err(payload) = result {
+        if !payload.is::<StopAssertions>() {
+            if messages.is_empty() {
+                panic::resume_unwind(payload);
+            }
+
+            messages.push(format!("\n\tblock panicked: {}", panic_message(&*payload)));
+        }
+    }
+
+    if !messages.is_empty() {
+        report_failures(build_report(&messages));
+    }
+}
+
+/// Passes the report of the failures in an `assert_all` block to the current reporter, or panics
+/// with it if no reporter has been set.
+#[track_caller]
+fn report_failures(report: String) {
+    let caller = Location::caller();
+
+    match reporter::current_reporter() {
+        Some(reporter) => {
+            reporter.report(&AssertionError {
+                description: None,
+                subject_name: None,
+                location: Some(format!("{}:{}", caller.file(), caller.line())),
+                expected: None,
+                actual: None,
+                message: Some(report),
+                diff: None,
+            })
+        }
+        None => panic!("{}", report),
+    }
+}
+
+/// Runs the provided block, collecting any assertion failures made on the current thread rather
+/// than panicking.
+///
+/// Panics from the block are caught and returned rather than propagated.
+pub(crate) fn collect_failures<F, R>(block: F) -> (thread::Result<R>, Vec<AssertionError>)
+    where F: FnOnce() -> R
+{
+    COLLECTED_FAILURES.with(|frames| frames.borrow_mut().push(Vec::new()));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(block));
+
+    let failures = COLLECTED_FAILURES.with(|frames| frames.borrow_mut().pop())
+        .unwrap_or_default();
+
+    (result, failures)
+}
+
+/// Runs the provided block, catching any panic from it.
+///
+/// Failures are not collected while the block runs, so a failing assertion inside the block
+/// panics as it would outside of an `assert_all` block.
+pub(crate) fn catch_panic<F, R>(block: F) -> thread::Result<R>
+    where F: FnOnce() -> R
+{
+    let frames = COLLECTED_FAILURES.with(|frames| frames.replace(Vec::new()));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(block));
+
+    COLLECTED_FAILURES.with(|collected| *collected.borrow_mut() = frames);
+
+    result
+}
+
+/// Records the failure if failures are being collected on the current thread.
+///
+/// Hands the failure back if nothing is collecting failures, in which case the caller should
+/// panic.
+pub(crate) fn collect(failure: AssertionError) -> Option<AssertionError> {
+    COLLECTED_FAILURES.with(|frames| match frames.borrow_mut().last_mut() {
+        Some(failures) => {
+            failures.push(failure);
+            None
+        }
+        None => Some(failure),
+    })
+}
+
+/// Returns true if failures are being collected on the current thread.
+pub(crate) fn is_collecting() -> bool {
+    COLLECTED_FAILURES.with(|frames| !frames.borrow().is_empty())
+}
+
+/// Unwinds out of the running `assert_all` block (or `check_that` call) without printing another
+/// panic message.
+pub(crate) fn stop() -> ! {
+    panic::resume_unwind(Box::new(StopAssertions))
+}
+
+fn build_report(failures: &[String]) -> String {
+    let mut report = format!("{} soft assertion(s) failed:", failures.len());
+
+    for (index, failure) in failures.iter().enumerate() {
+        report.push_str(&format!("\n\n{}){}", index + 1, failure));
+    }
+
+    report
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    match payload.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => "<unknown panic payload>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_not_panic_if_all_soft_assertions_pass() {
+        assert_all(|s| {
+            s.that(&1).is_equal_to(1);
+            s.that(&"Hello").starts_with("H");
+            s.that(&true).is_true();
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "2 soft assertion(s) failed:\
+                   \n\n1)\n\texpected: <2>\n\t but was: <1>\n\
+                   \n\tat location: src/soft.rs:")]
+    fn should_collect_every_failure_before_panicking() {
+        assert_all(|s| {
+            s.that(&1).is_equal_to(2);
+            s.that(&"Hello").starts_with("H");
+            s.that(&false).named("flag").is_true();
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "1)\n\tstatus:\n\texpected: <200>\n\t but was: <404>")]
+    fn should_keep_descriptions_of_soft_assertions() {
+        assert_all(|s| {
+            s.asserting("status").that(&404).is_equal_to(200);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "2 soft assertion(s) failed:")]
+    fn should_collect_failures_from_assert_that_inside_block() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_all(|_| {
+            assert_that(&test_map).has_length(2);
+            assert_that(&test_map).contains_entry("hello", "hey");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "1 soft assertion(s) failed:\
+                   \n\n1)\n\texpected: option[some]\n\t but was: option[none]\n")]
+    fn should_stop_block_if_assertion_cannot_continue() {
+        let option: Option<&str> = None;
+
+        assert_all(|s| {
+            s.that(&1).is_equal_to(1);
+            s.that(&option).is_some().is_equal_to("Hello");
+            panic!("should not be reached");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "2)\n\tblock panicked: something went wrong")]
+    fn should_report_unexpected_panic_with_collected_failures() {
+        assert_all(|s| {
+            s.that(&1).is_equal_to(2);
+            panic!("something went wrong");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_panic_immediately_outside_of_soft_assertions() {
+        assert_all(|s| {
+            s.that(&1).is_equal_to(1);
+        });
+
+        assert_that(&1).is_equal_to(2);
+    }
+}
@@ -1,12 +1,40 @@
-use super::{AssertionFailure, DescriptiveSpec, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec, Subject};
+use super::result::build_detail_message;
 
+use std::any;
 use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "regex")]
+use std::collections::HashMap;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
 
 pub trait StrAssertions {
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn is_empty(&mut self);
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
+    fn is_equal_to_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_equal_to_ignoring_whitespace<'r, E: Borrow<&'r str>>(&mut self,
+                                                               expected: E)
+                                                               -> &mut Self;
+    fn contains_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn starts_with_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn has_char_count(&mut self, expected: usize) -> &mut Self;
+    #[cfg(feature = "unicode-segmentation")]
+    fn has_grapheme_count(&mut self, expected: usize) -> &mut Self;
+    fn is_ascii(&mut self) -> &mut Self;
+    fn is_alphanumeric(&mut self) -> &mut Self;
+    fn is_blank(&mut self) -> &mut Self;
+    fn is_uppercase(&mut self) -> &mut Self;
+    fn contains_only_digits(&mut self) -> &mut Self;
 }
 
 impl<'s> StrAssertions for Spec<'s, &'s str> {
@@ -15,9 +43,12 @@ impl<'s> StrAssertions for Spec<'s, &'s str> {
     /// ```rust,ignore
     /// assert_that(&"Hello").starts_with(&"H");
     /// ```
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
         starts_with(self, subject, expected);
+
+        self
     }
 
     /// Asserts that the subject `&str` ends with the provided `&str`.
@@ -25,9 +56,12 @@ impl<'s> StrAssertions for Spec<'s, &'s str> {
     /// ```rust,ignore
     /// assert_that(&"Hello").ends_with(&"o");
     /// ```
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
         ends_with(self, subject, expected);
+
+        self
     }
 
     /// Asserts that the subject `&str` contains the provided `&str`.
@@ -35,112 +69,1259 @@ impl<'s> StrAssertions for Spec<'s, &'s str> {
     /// ```rust,ignore
     /// assert_that(&"Hello").contains(&"e");
     /// ```
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
+    #[track_caller]
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
+        contains(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` is empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"").is_empty();
+    /// ```
+    #[track_caller]
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        is_empty(self, subject);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").is_equal_to_ignoring_case(&"HELLO");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
+        is_equal_to_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`, ignoring differences in
+    /// whitespace. Leading and trailing whitespace is removed, and any other run of whitespace
+    /// is treated as a single space.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").is_equal_to_ignoring_whitespace(&"  Hello ");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_ignoring_whitespace<'r, E: Borrow<&'r str>>(&mut self,
+                                                               expected: E)
+                                                               -> &mut Self {
+        let subject = &*self.subject;
+        is_equal_to_ignoring_whitespace(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` contains the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").contains_ignoring_case(&"ELL");
+    /// ```
+    #[track_caller]
+    fn contains_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
+        contains_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` starts with the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").starts_with_ignoring_case(&"h");
+    /// ```
+    #[track_caller]
+    fn starts_with_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &*self.subject;
+        starts_with_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` has the expected length in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo").has_length(6);
+    /// ```
+    #[track_caller]
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let subject = &*self.subject;
+        has_count(self, subject.len(), expected, "byte(s)");
+
+        self
+    }
+
+    /// Asserts that the subject `&str` contains the expected number of `char`s.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo").has_char_count(5);
+    /// ```
+    #[track_caller]
+    fn has_char_count(&mut self, expected: usize) -> &mut Self {
+        let subject = &*self.subject;
+        has_count(self, subject.chars().count(), expected, "char(s)");
+
+        self
+    }
+
+    /// Asserts that the subject `&str` contains the expected number of extended grapheme
+    /// clusters, which is closer to the number of characters a reader would see.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"e\u{301}").has_grapheme_count(1);
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    #[track_caller]
+    fn has_grapheme_count(&mut self, expected: usize) -> &mut Self {
+        let subject = &*self.subject;
+        has_count(self, subject.graphemes(true).count(), expected, "grapheme(s)");
+
+        self
+    }
+
+    /// Asserts that every character of the subject `&str` is ASCII.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"hello").is_ascii();
+    /// ```
+    #[track_caller]
+    fn is_ascii(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        has_only_chars(self, subject, CharClass::Ascii);
+
+        self
+    }
+
+    /// Asserts that every character of the subject `&str` is alphanumeric.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"abc123").is_alphanumeric();
+    /// ```
+    #[track_caller]
+    fn is_alphanumeric(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        has_only_chars(self, subject, CharClass::Alphanumeric);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` is empty or contains only whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&" \t\n").is_blank();
+    /// ```
+    #[track_caller]
+    fn is_blank(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        has_only_chars(self, subject, CharClass::Whitespace);
+
+        self
+    }
+
+    /// Asserts that the subject `&str` contains no lowercase characters.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"HELLO, WORLD").is_uppercase();
+    /// ```
+    #[track_caller]
+    fn is_uppercase(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        has_only_chars(self, subject, CharClass::NotLowercase);
+
+        self
+    }
+
+    /// Asserts that every character of the subject `&str` is an ASCII digit.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"0123").contains_only_digits();
+    /// ```
+    #[track_caller]
+    fn contains_only_digits(&mut self) -> &mut Self {
+        let subject = &*self.subject;
+        has_only_chars(self, subject, CharClass::Digit);
+
+        self
+    }
+}
+
+impl<'s> StrAssertions for Spec<'s, String> {
+    /// Asserts that the subject `String` starts with the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).starts_with(&"H");
+    /// ```
+    #[track_caller]
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
+        starts_with(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` ends with the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).ends_with(&"o");
+    /// ```
+    #[track_caller]
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
+        ends_with(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` contains the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).contains(&"e");
+    /// ```
+    #[track_caller]
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
         contains(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` is empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"".to_owned()).is_empty();
+    /// ```
+    #[track_caller]
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        is_empty(self, subject);
+
+        self
+    }
+
+    /// Asserts that the subject `String` is equal to the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).is_equal_to_ignoring_case(&"HELLO");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
+        is_equal_to_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` is equal to the provided `&str`, ignoring differences in
+    /// whitespace. Leading and trailing whitespace is removed, and any other run of whitespace
+    /// is treated as a single space.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).is_equal_to_ignoring_whitespace(&"  Hello ");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_ignoring_whitespace<'r, E: Borrow<&'r str>>(&mut self,
+                                                               expected: E)
+                                                               -> &mut Self {
+        let subject = &self.subject;
+        is_equal_to_ignoring_whitespace(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` contains the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).contains_ignoring_case(&"ELL");
+    /// ```
+    #[track_caller]
+    fn contains_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
+        contains_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` starts with the provided `&str`, ignoring case.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).starts_with_ignoring_case(&"h");
+    /// ```
+    #[track_caller]
+    fn starts_with_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = &self.subject;
+        starts_with_ignoring_case(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the subject `String` has the expected length in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo".to_owned()).has_length(6);
+    /// ```
+    #[track_caller]
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let subject = &self.subject;
+        has_count(self, subject.len(), expected, "byte(s)");
+
+        self
+    }
+
+    /// Asserts that the subject `String` contains the expected number of `char`s.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo".to_owned()).has_char_count(5);
+    /// ```
+    #[track_caller]
+    fn has_char_count(&mut self, expected: usize) -> &mut Self {
+        let subject = &self.subject;
+        has_count(self, subject.chars().count(), expected, "char(s)");
+
+        self
+    }
+
+    /// Asserts that the subject `String` contains the expected number of extended grapheme
+    /// clusters, which is closer to the number of characters a reader would see.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"e\u{301}".to_owned()).has_grapheme_count(1);
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    #[track_caller]
+    fn has_grapheme_count(&mut self, expected: usize) -> &mut Self {
+        let subject = &self.subject;
+        has_count(self, subject.graphemes(true).count(), expected, "grapheme(s)");
+
+        self
+    }
+
+    /// Asserts that every character of the subject `String` is ASCII.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"hello".to_owned()).is_ascii();
+    /// ```
+    #[track_caller]
+    fn is_ascii(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        has_only_chars(self, subject, CharClass::Ascii);
+
+        self
+    }
+
+    /// Asserts that every character of the subject `String` is alphanumeric.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"abc123".to_owned()).is_alphanumeric();
+    /// ```
+    #[track_caller]
+    fn is_alphanumeric(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        has_only_chars(self, subject, CharClass::Alphanumeric);
+
+        self
+    }
+
+    /// Asserts that the subject `String` is empty or contains only whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&" \t\n".to_owned()).is_blank();
+    /// ```
+    #[track_caller]
+    fn is_blank(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        has_only_chars(self, subject, CharClass::Whitespace);
+
+        self
+    }
+
+    /// Asserts that the subject `String` contains no lowercase characters.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"HELLO, WORLD".to_owned()).is_uppercase();
+    /// ```
+    #[track_caller]
+    fn is_uppercase(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        has_only_chars(self, subject, CharClass::NotLowercase);
+
+        self
+    }
+
+    /// Asserts that every character of the subject `String` is an ASCII digit.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"0123".to_owned()).contains_only_digits();
+    /// ```
+    #[track_caller]
+    fn contains_only_digits(&mut self) -> &mut Self {
+        let subject = &self.subject;
+        has_only_chars(self, subject, CharClass::Digit);
+
+        self
+    }
+}
+
+pub trait LineAssertions<'s> {
+    fn has_line_count(&mut self, expected: usize) -> &mut Self;
+    fn contains_line<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_equal_to_lines<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_equal_to_lines_with<'r, E: Borrow<&'r str>>(&mut self,
+                                                      expected: E,
+                                                      options: LineOptions)
+                                                      -> &mut Self;
+    fn lines(&self) -> Spec<'s, Vec<&'s str>>;
+}
+
+/// Controls how lines are compared by `is_equal_to_lines_with`.
+///
+/// Lines are split in the same way as `str::lines`, as with the other line assertions. By default
+/// lines must match exactly, including any trailing whitespace and the carriage return of a CRLF
+/// line ending.
+///
+/// ```rust,ignore
+/// LineOptions::new().ignoring_trailing_whitespace().ignoring_line_endings()
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LineOptions {
+    ignore_trailing_whitespace: bool,
+    ignore_line_endings: bool,
+}
+
+impl LineOptions {
+    pub fn new() -> LineOptions {
+        LineOptions::default()
+    }
+
+    /// Ignores whitespace at the end of each line.
+    pub fn ignoring_trailing_whitespace(mut self) -> LineOptions {
+        self.ignore_trailing_whitespace = true;
+        self
+    }
+
+    /// Treats CRLF and LF line endings as the same.
+    pub fn ignoring_line_endings(mut self) -> LineOptions {
+        self.ignore_line_endings = true;
+        self
+    }
+
+    /// Splits the value into lines in the same way as `str::lines`, keeping the carriage return
+    /// of a CRLF line ending unless line endings are ignored.
+    fn split<'a>(&self, value: &'a str) -> Vec<&'a str> {
+        value.lines()
+            .map(|line| {
+                let line = if self.ignore_line_endings {
+                    line
+                } else {
+                    with_carriage_return(value, line)
+                };
+
+                if self.ignore_trailing_whitespace {
+                    line.trim_end()
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        match (self.ignore_trailing_whitespace, self.ignore_line_endings) {
+            (true, true) => " (ignoring trailing whitespace and line endings)".to_string(),
+            (true, false) => " (ignoring trailing whitespace)".to_string(),
+            (false, true) => " (ignoring line endings)".to_string(),
+            (false, false) => "".to_string(),
+        }
+    }
+}
+
+impl<'s> LineAssertions<'s> for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` has the expected number of lines. Lines are split in the
+    /// same way as `str::lines`, so a trailing line ending does not start another line.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld").has_line_count(2);
+    /// ```
+    #[track_caller]
+    fn has_line_count(&mut self, expected: usize) -> &mut Self {
+        let subject = *self.subject;
+        has_line_count(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that one of the lines of the subject `&str` is equal to the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld").contains_line(&"World");
+    /// ```
+    #[track_caller]
+    fn contains_line<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = *self.subject;
+        contains_line(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the lines of the subject `&str` are equal to the lines of the provided
+    /// `&str`. On failure, a line-numbered diff is added to the panic message.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld").is_equal_to_lines(&"Hello\nWorld");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_lines<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        self.is_equal_to_lines_with(expected, LineOptions::default())
+    }
+
+    /// Asserts that the lines of the subject `&str` are equal to the lines of the provided
+    /// `&str`, compared using the provided `LineOptions`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld")
+    ///     .is_equal_to_lines_with(&"Hello\r\nWorld", LineOptions::new().ignoring_line_endings());
+    /// ```
+    #[track_caller]
+    fn is_equal_to_lines_with<'r, E: Borrow<&'r str>>(&mut self,
+                                                      expected: E,
+                                                      options: LineOptions)
+                                                      -> &mut Self {
+        let subject = *self.subject;
+        is_equal_to_lines(self, subject, expected, options);
+
+        self
+    }
+
+    /// Creates a new `Spec` for the lines of the subject `&str`, so that they can be checked
+    /// with the iterator assertions. Lines are split in the same way as `str::lines`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld").lines().contains(&"World");
+    /// ```
+    fn lines(&self) -> Spec<'s, Vec<&'s str>> {
+        let subject: &'s str = *self.subject;
+
+        Spec {
+            subject: Subject::Owned(subject.lines().collect()),
+            subject_name: self.subject_name.clone(),
+            location: self.location.clone(),
+            description: self.description,
+            negated: self.negated,
+        }
+    }
+}
+
+impl<'s> LineAssertions<'s> for Spec<'s, String> {
+    /// Asserts that the subject `String` has the expected number of lines. Lines are split in the
+    /// same way as `str::lines`, so a trailing line ending does not start another line.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld".to_owned()).has_line_count(2);
+    /// ```
+    #[track_caller]
+    fn has_line_count(&mut self, expected: usize) -> &mut Self {
+        let subject = self.subject.as_str();
+        has_line_count(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that one of the lines of the subject `String` is equal to the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld".to_owned()).contains_line(&"World");
+    /// ```
+    #[track_caller]
+    fn contains_line<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject.as_str();
+        contains_line(self, subject, expected);
+
+        self
+    }
+
+    /// Asserts that the lines of the subject `String` are equal to the lines of the provided
+    /// `&str`. On failure, a line-numbered diff is added to the panic message.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld".to_owned()).is_equal_to_lines(&"Hello\nWorld");
+    /// ```
+    #[track_caller]
+    fn is_equal_to_lines<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        self.is_equal_to_lines_with(expected, LineOptions::default())
+    }
+
+    /// Asserts that the lines of the subject `String` are equal to the lines of the provided
+    /// `&str`, compared using the provided `LineOptions`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld".to_owned())
+    ///     .is_equal_to_lines_with(&"Hello\r\nWorld", LineOptions::new().ignoring_line_endings());
+    /// ```
+    #[track_caller]
+    fn is_equal_to_lines_with<'r, E: Borrow<&'r str>>(&mut self,
+                                                      expected: E,
+                                                      options: LineOptions)
+                                                      -> &mut Self {
+        let subject = self.subject.as_str();
+        is_equal_to_lines(self, subject, expected, options);
+
+        self
+    }
+
+    /// Creates a new `Spec` for the lines of the subject `String`, so that they can be checked
+    /// with the iterator assertions. Lines are split in the same way as `str::lines`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello\nWorld".to_owned()).lines().contains(&"World");
+    /// ```
+    #[track_caller]
+    fn lines(&self) -> Spec<'s, Vec<&'s str>> {
+        Spec {
+            subject: Subject::Owned(self.projected_subject("lines").lines().collect()),
+            subject_name: self.subject_name.clone(),
+            location: self.location.clone(),
+            description: self.description,
+            negated: self.negated,
+        }
+    }
+}
+
+pub trait ParsingAssertions<'s> {
+    fn parses_as<T>(&mut self) -> Spec<'s, T>
+        where T: FromStr + 's,
+              T::Err: Debug;
+    fn does_not_parse_as<T>(&mut self) -> &mut Self
+        where T: FromStr + Debug,
+              T::Err: Debug;
+}
+
+impl<'s> ParsingAssertions<'s> for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` can be parsed as the given type, using its `FromStr`
+    /// implementation.
+    ///
+    /// This will return a new `Spec` containing the parsed value.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42").parses_as::<u32>().is_greater_than(40);
+    /// ```
+    #[track_caller]
+    fn parses_as<T>(&mut self) -> Spec<'s, T>
+        where T: FromStr + 's,
+              T::Err: Debug
+    {
+        let subject: &'s str = *self.subject;
+        parses_as(self, subject)
+    }
+
+    /// Asserts that the subject `&str` cannot be parsed as the given type, using its
+    /// `FromStr` implementation.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42").does_not_parse_as::<bool>();
+    /// ```
+    #[track_caller]
+    fn does_not_parse_as<T>(&mut self) -> &mut Self
+        where T: FromStr + Debug,
+              T::Err: Debug
+    {
+        let subject = &*self.subject;
+        does_not_parse_as::<T, _>(self, subject);
+
+        self
+    }
+}
+
+impl<'s> ParsingAssertions<'s> for Spec<'s, String> {
+    /// Asserts that the subject `String` can be parsed as the given type, using its `FromStr`
+    /// implementation.
+    ///
+    /// This will return a new `Spec` containing the parsed value.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42".to_owned()).parses_as::<u32>().is_greater_than(40);
+    /// ```
+    #[track_caller]
+    fn parses_as<T>(&mut self) -> Spec<'s, T>
+        where T: FromStr + 's,
+              T::Err: Debug
+    {
+        let subject = self.subject.as_str();
+        parses_as(self, subject)
+    }
+
+    /// Asserts that the subject `String` cannot be parsed as the given type, using its
+    /// `FromStr` implementation.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42".to_owned()).does_not_parse_as::<bool>();
+    /// ```
+    #[track_caller]
+    fn does_not_parse_as<T>(&mut self) -> &mut Self
+        where T: FromStr + Debug,
+              T::Err: Debug
+    {
+        let subject = self.subject.as_str();
+        does_not_parse_as::<T, _>(self, subject);
+
+        self
+    }
+}
+
+#[cfg(feature = "regex")]
+pub trait RegexAssertions<'s> {
+    fn matches_regex(&mut self, pattern: &str) -> &mut Self;
+    fn does_not_match_regex(&mut self, pattern: &str) -> &mut Self;
+    fn contains_match(&mut self, pattern: &str) -> &mut Self;
+    fn captures(&mut self, pattern: &str) -> Spec<'s, HashMap<String, String>>;
+}
+
+#[cfg(feature = "regex")]
+impl<'s> RegexAssertions<'s> for Spec<'s, &'s str> {
+    /// Asserts that the whole of the subject `&str` matches the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06-01").matches_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    #[track_caller]
+    fn matches_regex(&mut self, pattern: &str) -> &mut Self {
+        let subject = *self.subject;
+        matches_regex(self, subject, pattern);
+
+        self
+    }
+
+    /// Asserts that the whole of the subject `&str` does not match the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06").does_not_match_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    #[track_caller]
+    fn does_not_match_regex(&mut self, pattern: &str) -> &mut Self {
+        self.toggle_negation().matches_regex(pattern).toggle_negation()
+    }
+
+    /// Asserts that some part of the subject `&str` matches the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"released on 2017-06-01").contains_match(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    #[track_caller]
+    fn contains_match(&mut self, pattern: &str) -> &mut Self {
+        let subject = *self.subject;
+        contains_match(self, subject, pattern);
+
+        self
+    }
+
+    /// Asserts that some part of the subject `&str` matches the provided regex.
+    ///
+    /// This will return a new `Spec` containing the named groups of the first match, keyed by
+    /// name. Groups which did not take part in the match are left out.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06-01").captures(r"(?P<year>\d{4})-(?P<month>\d{2})");
+    /// ```
+    #[track_caller]
+    fn captures(&mut self, pattern: &str) -> Spec<'s, HashMap<String, String>> {
+        let subject = *self.subject;
+        captures(self, subject, pattern)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'s> RegexAssertions<'s> for Spec<'s, String> {
+    /// Asserts that the whole of the subject `String` matches the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06-01".to_owned()).matches_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    #[track_caller]
+    fn matches_regex(&mut self, pattern: &str) -> &mut Self {
+        let subject = &*self.subject;
+        matches_regex(self, subject, pattern);
+
+        self
+    }
+
+    /// Asserts that the whole of the subject `String` does not match the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06".to_owned()).does_not_match_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    #[track_caller]
+    fn does_not_match_regex(&mut self, pattern: &str) -> &mut Self {
+        self.toggle_negation().matches_regex(pattern).toggle_negation()
+    }
+
+    /// Asserts that some part of the subject `String` matches the provided regex.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"released on 2017-06-01".to_owned()).contains_match(r"\d{4}");
+    /// ```
+    #[track_caller]
+    fn contains_match(&mut self, pattern: &str) -> &mut Self {
+        let subject = &*self.subject;
+        contains_match(self, subject, pattern);
+
+        self
+    }
+
+    /// Asserts that some part of the subject `String` matches the provided regex.
+    ///
+    /// This will return a new `Spec` containing the named groups of the first match, keyed by
+    /// name. Groups which did not take part in the match are left out.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"2017-06-01".to_owned()).captures(r"(?P<year>\d{4})-(?P<month>\d{2})");
+    /// ```
+    #[track_caller]
+    fn captures(&mut self, pattern: &str) -> Spec<'s, HashMap<String, String>> {
+        let subject = &*self.subject;
+        captures(self, subject, pattern)
+    }
+}
+
+/// Extends a line returned by `str::lines` to include the carriage return of its line ending, if
+/// it had a CRLF line ending.
+fn with_carriage_return<'a>(value: &'a str, line: &'a str) -> &'a str {
+    let start = line.as_ptr() as usize - value.as_ptr() as usize;
+    let end = start + line.len();
+
+    if value[end..].starts_with("\r\n") {
+        &value[start..end + 1]
+    } else {
+        line
+    }
+}
+
+#[track_caller]
+fn has_line_count<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, expected: usize) {
+    let line_count = subject.lines().count();
+
+    if !spec.passes(line_count == expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string with <{}> line(s)", expected))
+            .with_negated_expected(format!("string without <{}> line(s)", expected))
+            .with_actual(format!("<{}> line(s)", line_count))
+            .fail();
+    }
+}
+
+#[track_caller]
+fn contains_line<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                     subject: &str,
+                                                                     expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    match subject.lines().position(|line| line == *borrowed_expected) {
+        Some(index) => {
+            if spec.is_negated() {
+                AssertionFailure::from_spec(spec)
+                    .with_expected(format!("string containing line <{:?}>", borrowed_expected))
+                    .with_negated_expected(format!("string not containing line <{:?}>",
+                                                   borrowed_expected))
+                    .with_actual(format!("line <{}> equal to it", index + 1))
+                    .fail();
+            }
+        }
+        None => {
+            if !spec.is_negated() {
+                AssertionFailure::from_spec(spec)
+                    .with_expected(format!("string containing line <{:?}>", borrowed_expected))
+                    .with_actual(format!("<{}> line(s), none equal to it",
+                                         subject.lines().count()))
+                    .fail();
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn is_equal_to_lines<'r, 's, S, E>(spec: &'s S, subject: &str, expected: E, options: LineOptions)
+    where S: DescriptiveSpec<'s>,
+          E: Borrow<&'r str>
+{
+    let expected_lines = options.split(expected.borrow());
+    let actual_lines = options.split(subject);
+
+    if spec.passes(expected_lines == actual_lines) {
+        return;
+    }
+
+    if spec.is_negated() {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string with lines equal to the expected lines{}",
+                                   options.describe()))
+            .with_negated_expected(format!("string with lines differing from the expected \
+                                            lines{}",
+                                           options.describe()))
+            .with_actual("equal lines".to_string())
+            .fail();
+        return;
+    }
+
+    let first_difference = expected_lines.iter()
+        .zip(actual_lines.iter())
+        .take_while(|&(expected, actual)| expected == actual)
+        .count();
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("string with lines equal to the expected lines{}",
+                               options.describe()))
+        .with_actual(format!("string differing from line <{}>", first_difference + 1))
+        .with_line_diff(&expected_lines, &actual_lines)
+        .fail();
+}
+
+#[track_caller]
+fn parses_as<'s, S, T>(spec: &Spec<'s, S>, subject: &str) -> Spec<'s, T>
+    where T: FromStr + 's,
+          T::Err: Debug
+{
+    spec.ensure_not_negated("parses_as");
+
+    match subject.parse::<T>() {
+        Ok(value) => {
+            Spec {
+                subject: Subject::Owned(value),
+                subject_name: spec.subject_name.clone(),
+                location: spec.location.clone(),
+                description: spec.description,
+                negated: false,
+            }
+        }
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("<{:?}> to parse as <{}>", subject, any::type_name::<T>()))
+                .with_actual(build_detail_message("err", error))
+                .fail_and_stop();
+        }
+    }
+}
+
+#[track_caller]
+fn does_not_parse_as<'s, T, S>(spec: &'s S, subject: &str)
+    where T: FromStr + Debug,
+          T::Err: Debug,
+          S: DescriptiveSpec<'s>
+{
+    let result = subject.parse::<T>();
+
+    if !spec.passes(result.is_err()) {
+        let type_name = any::type_name::<T>();
+        let actual = match result {
+            Ok(value) => build_detail_message("ok", value),
+            Err(error) => build_detail_message("err", error),
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("<{:?}> to not parse as <{}>", subject, type_name))
+            .with_negated_expected(format!("<{:?}> to parse as <{}>", subject, type_name))
+            .with_actual(actual)
+            .fail();
+    }
+}
+
+#[track_caller]
+fn has_count<'s, S: DescriptiveSpec<'s>>(spec: &'s S, count: usize, expected: usize, unit: &str) {
+    if !spec.passes(count == expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string with <{}> {}", expected, unit))
+            .with_negated_expected(format!("string without <{}> {}", expected, unit))
+            .with_actual(format!("<{}> {}", count, unit))
+            .fail();
     }
+}
 
-    /// Asserts that the subject `&str` is empty.
-    ///
-    /// ```rust,ignore
-    /// assert_that(&"").is_empty();
-    /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
-        is_empty(self, subject);
-    }
+/// The classes of character which `has_only_chars` can check for.
+#[derive(Clone, Copy, Debug)]
+enum CharClass {
+    Ascii,
+    Alphanumeric,
+    Whitespace,
+    NotLowercase,
+    Digit,
 }
 
-impl<'s> StrAssertions for Spec<'s, String> {
-    /// Asserts that the subject `String` starts with the provided `&str`.
-    ///
-    /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).starts_with(&"H");
-    /// ```
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        starts_with(self, subject, expected);
+impl CharClass {
+    fn contains(self, character: char) -> bool {
+        match self {
+            CharClass::Ascii => character.is_ascii(),
+            CharClass::Alphanumeric => character.is_alphanumeric(),
+            CharClass::Whitespace => character.is_whitespace(),
+            CharClass::NotLowercase => !character.is_lowercase(),
+            CharClass::Digit => character.is_ascii_digit(),
+        }
     }
 
-    /// Asserts that the subject `String` ends with the provided `&str`.
-    ///
-    /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).ends_with(&"o");
-    /// ```
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        ends_with(self, subject, expected);
+    fn describe(self) -> &'static str {
+        match self {
+            CharClass::Ascii => "an ASCII string",
+            CharClass::Alphanumeric => "an alphanumeric string",
+            CharClass::Whitespace => "a blank string",
+            CharClass::NotLowercase => "an uppercase string",
+            CharClass::Digit => "a string containing only digits",
+        }
     }
 
-    /// Asserts that the subject `String` contains the provided `&str`.
-    ///
-    /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).contains(&"e");
-    /// ```
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        contains(self, subject, expected);
+    fn describe_negated(self) -> &'static str {
+        match self {
+            CharClass::Ascii => "a string which is not ASCII",
+            CharClass::Alphanumeric => "a string which is not alphanumeric",
+            CharClass::Whitespace => "a string which is not blank",
+            CharClass::NotLowercase => "a string which is not uppercase",
+            CharClass::Digit => "a string containing more than digits",
+        }
     }
+}
 
-    /// Asserts that the subject `String` is empty.
-    ///
-    /// ```rust,ignore
-    /// assert_that(&"".to_owned()).is_empty();
-    /// ```
-    fn is_empty(&mut self) {
-        let subject = &self.subject;
-        is_empty(self, subject);
+/// Checks that every character of the subject is in the class, reporting the first character
+/// which is not rather than the whole subject.
+#[track_caller]
+fn has_only_chars<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, class: CharClass) {
+    let offending = subject.char_indices()
+        .enumerate()
+        .find(|&(_, (_, character))| !class.contains(character));
+
+    if spec.passes(offending.is_none()) {
+        return;
     }
+
+    let actual = match offending {
+        Some((char_index, (byte_index, character))) => {
+            format!("<{:?}> at char index <{}> (byte index <{}>)",
+                    character,
+                    char_index,
+                    byte_index)
+        }
+        None => format!("<{:?}>", subject),
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(class.describe().to_string())
+        .with_negated_expected(class.describe_negated().to_string())
+        .with_actual(actual)
+        .fail();
 }
 
+#[track_caller]
 fn starts_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
                                                                    subject: &str,
                                                                    expected: E) {
     let borrowed_expected = expected.borrow();
 
-    if !subject.starts_with(borrowed_expected) {
+    if !spec.passes(subject.starts_with(borrowed_expected)) {
         AssertionFailure::from_spec(spec)
             .with_expected(format!("string starting with <{:?}>", borrowed_expected))
+            .with_negated_expected(format!("string not starting with <{:?}>", borrowed_expected))
             .with_actual(format!("<{:?}>", subject))
             .fail();
     }
 }
 
+#[track_caller]
 fn ends_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
                                                                  subject: &str,
                                                                  expected: E) {
     let borrowed_expected = expected.borrow();
 
-    if !subject.ends_with(borrowed_expected) {
+    if !spec.passes(subject.ends_with(borrowed_expected)) {
         AssertionFailure::from_spec(spec)
             .with_expected(format!("string ending with <{:?}>", borrowed_expected))
+            .with_negated_expected(format!("string not ending with <{:?}>", borrowed_expected))
             .with_actual(format!("<{:?}>", subject))
             .fail();
     }
 }
 
+#[track_caller]
 fn contains<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
                                                                 subject: &str,
                                                                 expected: E) {
     let borrowed_expected = expected.borrow();
 
-    if !subject.contains(borrowed_expected) {
+    if !spec.passes(subject.contains(borrowed_expected)) {
         AssertionFailure::from_spec(spec)
             .with_expected(format!("string containing <{:?}>", borrowed_expected))
+            .with_negated_expected(format!("string not containing <{:?}>", borrowed_expected))
             .with_actual(format!("<{:?}>", subject))
             .fail();
     }
 }
 
+#[track_caller]
 fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
-    if !subject.is_empty() {
+    if !spec.passes(subject.is_empty()) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("an empty string".to_string())
+            .with_negated_expected("a non-empty string".to_string())
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+#[track_caller]
+fn is_equal_to_ignoring_case<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                                 subject: &str,
+                                                                                 expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !spec.passes(subject.to_lowercase() == borrowed_expected.to_lowercase()) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string equal to <{:?}> (ignoring case)", borrowed_expected))
+            .with_negated_expected(format!("string not equal to <{:?}> (ignoring case)",
+                                           borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+#[track_caller]
+fn is_equal_to_ignoring_whitespace<'r, 's, S, E>(spec: &'s S, subject: &str, expected: E)
+    where S: DescriptiveSpec<'s>,
+          E: Borrow<&'r str>
+{
+    let borrowed_expected = expected.borrow();
+
+    if !spec.passes(normalise_whitespace(subject) == normalise_whitespace(borrowed_expected)) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string equal to <{:?}> (ignoring whitespace)",
+                                   borrowed_expected))
+            .with_negated_expected(format!("string not equal to <{:?}> (ignoring whitespace)",
+                                           borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+#[track_caller]
+fn contains_ignoring_case<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                              subject: &str,
+                                                                              expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !spec.passes(subject.to_lowercase().contains(&borrowed_expected.to_lowercase())) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string containing <{:?}> (ignoring case)", borrowed_expected))
+            .with_negated_expected(format!("string not containing <{:?}> (ignoring case)",
+                                           borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+#[track_caller]
+fn starts_with_ignoring_case<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                                 subject: &str,
+                                                                                 expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !spec.passes(subject.to_lowercase().starts_with(&borrowed_expected.to_lowercase())) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string starting with <{:?}> (ignoring case)",
+                                   borrowed_expected))
+            .with_negated_expected(format!("string not starting with <{:?}> (ignoring case)",
+                                           borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+/// Trims the value and replaces every run of whitespace with a single space.
+fn normalise_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Compiles the regex, failing the assertion if it is invalid. The assertion cannot carry on
+/// without a regex, so the failure stops it as with `fail_and_stop`.
+#[cfg(feature = "regex")]
+#[track_caller]
+fn compile_regex<'s, S: DescriptiveSpec<'s>>(spec: &'s S, source: &str, pattern: &str) -> Regex {
+    match Regex::new(source) {
+        Ok(regex) => regex,
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("valid regex <{:?}>", pattern))
+                .with_negated_expected(format!("valid regex <{:?}>", pattern))
+                .with_actual(error.to_string().replace('\n', "\n\t"))
+                .fail_and_stop();
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+#[track_caller]
+fn matches_regex<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, pattern: &str) {
+    // Anchor the whole pattern, so that alternations must also match the entire subject.
+    let regex = compile_regex(spec, &format!("^(?:{})$", pattern), pattern);
+
+    if !spec.passes(regex.is_match(subject)) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string matching regex <{:?}>", pattern))
+            .with_negated_expected(format!("string not matching regex <{:?}>", pattern))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+#[cfg(feature = "regex")]
+#[track_caller]
+fn contains_match<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, pattern: &str) {
+    let regex = compile_regex(spec, pattern, pattern);
+
+    if !spec.passes(regex.is_match(subject)) {
         AssertionFailure::from_spec(spec)
-            .with_expected(format!("an empty string"))
+            .with_expected(format!("string containing a match for regex <{:?}>", pattern))
+            .with_negated_expected(format!("string not containing a match for regex <{:?}>",
+                                           pattern))
             .with_actual(format!("<{:?}>", subject))
             .fail();
     }
 }
 
+#[cfg(feature = "regex")]
+#[track_caller]
+fn captures<'s, S>(spec: &Spec<'s, S>,
+                   subject: &str,
+                   pattern: &str)
+                   -> Spec<'s, HashMap<String, String>> {
+    spec.ensure_not_negated("captures");
+
+    let regex = compile_regex(spec, pattern, pattern);
+
+    let captures = match regex.captures(subject) {
+        Some(captures) => captures,
+        None => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("string containing a match for regex <{:?}>", pattern))
+                .with_actual(format!("<{:?}>", subject))
+                .fail_and_stop();
+        }
+    };
+
+    let named_groups = regex.capture_names()
+        .flatten()
+        .filter_map(|name| {
+            captures.name(name).map(|group| (name.to_string(), group.as_str().to_string()))
+        })
+        .collect();
+
+    Spec {
+        subject: Subject::Owned(named_groups),
+        subject_name: spec.subject_name.clone(),
+        location: spec.location.clone(),
+        description: spec.description,
+        negated: false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -284,4 +1465,404 @@ mod tests {
         assert_that(&value).is_empty();
     }
 
+    #[test]
+    fn should_not_panic_if_negated_string_assertions_fail() {
+        let value = "Hello";
+        assert_that(&value).not().starts_with("W");
+        assert_that(&value).not().ends_with("W");
+        assert_that(&value).not().contains("W");
+        assert_that(&value).not().is_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string not starting with <\"H\">\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_string_starts_with_value_when_negated() {
+        let value = "Hello".to_owned();
+        assert_that(&value).not().starts_with("H");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"x\">\n\t but was: <\"Hello\">")]
+    fn should_allow_string_assertions_to_be_chained() {
+        let value = "Hello";
+        assert_that(&value).starts_with("H").ends_with("o").contains("ll");
+        assert_that(&value).starts_with("H").contains("x").ends_with("o");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_case() {
+        let value = "Hello World";
+        assert_that(&value).is_equal_to_ignoring_case("hello WORLD");
+        assert_that(&value.to_owned()).is_equal_to_ignoring_case("HELLO world");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"hello\"> (ignoring case)\
+                   \n\t but was: <\"Hello World\">")]
+    fn should_panic_if_str_is_not_equal_ignoring_case() {
+        let value = "Hello World";
+        assert_that(&value).is_equal_to_ignoring_case("hello");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_whitespace() {
+        let value = "  fn main() {\n\t    run();\n}\n";
+        assert_that(&value).is_equal_to_ignoring_whitespace("fn main() { run(); }");
+        assert_that(&value.to_owned()).is_equal_to_ignoring_whitespace("fn  main()\n{ run();\n}");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"fnmain()\"> (ignoring whitespace)\
+                   \n\t but was: <\" fn main() \">")]
+    fn should_panic_if_str_is_not_equal_ignoring_whitespace() {
+        let value = " fn main() ";
+        assert_that(&value).is_equal_to_ignoring_whitespace("fnmain()");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_contains_value_ignoring_case() {
+        let value = "Hello World";
+        assert_that(&value).contains_ignoring_case("O w");
+        assert_that(&value.to_owned()).not().contains_ignoring_case("x");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"xyz\"> (ignoring case)\
+                   \n\t but was: <\"Hello World\">")]
+    fn should_panic_if_string_does_not_contain_value_ignoring_case() {
+        let value = "Hello World".to_owned();
+        assert_that(&value).contains_ignoring_case("xyz");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_starts_with_value_ignoring_case() {
+        let value = "Hello World";
+        assert_that(&value).starts_with_ignoring_case("hELLO");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string not starting with <\"HELLO\"> (ignoring case)\
+                   \n\t but was: <\"Hello World\">")]
+    fn should_panic_if_string_starts_with_value_ignoring_case_when_negated() {
+        let value = "Hello World".to_owned();
+        assert_that(&value).not().starts_with_ignoring_case("HELLO");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_line_count() {
+        let value = "one\ntwo\r\nthree\n";
+        assert_that(&value).has_line_count(3);
+        assert_that(&value.to_owned()).not().has_line_count(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <2> line(s)\n\t but was: <3> line(s)")]
+    fn should_panic_if_str_does_not_have_line_count() {
+        let value = "one\ntwo\nthree";
+        assert_that(&value).has_line_count(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_str_contains_line() {
+        let value = "one\ntwo\nthree";
+        assert_that(&value).contains_line("two");
+        assert_that(&value.to_owned()).not().contains_line("tw");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing line <\"four\">\
+                   \n\t but was: <3> line(s), none equal to it")]
+    fn should_panic_if_string_does_not_contain_line() {
+        let value = "one\ntwo\nthree".to_owned();
+        assert_that(&value).contains_line("four");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string not containing line <\"two\">\
+                   \n\t but was: line <2> equal to it")]
+    fn should_panic_if_str_contains_line_when_negated() {
+        let value = "one\ntwo\nthree";
+        assert_that(&value).not().contains_line("two");
+    }
+
+    #[test]
+    fn should_allow_iterator_assertions_on_lines() {
+        let value = "one\ntwo\nthree";
+        assert_that(&value).lines().contains("two").has_length(3);
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_to_lines() {
+        let value = "one\ntwo\n";
+        assert_that(&value).is_equal_to_lines("one\ntwo");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with lines equal to the expected lines\
+                   \n\t but was: string differing from line <2>\
+                   \n\n\tdiff (- expected, + actual):\n\t  1 | one\n\t- 2 | two\n\t+ 2 | TWO\
+                   \n\t  3 | three")]
+    fn should_panic_with_line_diff_if_string_is_not_equal_to_lines() {
+        let value = "one\nTWO\nthree".to_owned();
+        assert_that(&value).is_equal_to_lines("one\ntwo\nthree");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\t- 1 | one\n\t+ 1 | one\\r")]
+    fn should_show_carriage_returns_in_line_diff() {
+        let value = "one\r\ntwo";
+        assert_that(&value).is_equal_to_lines("one\ntwo");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_to_lines_ignoring_differences() {
+        let value = "one  \r\ntwo\r\n";
+        assert_that(&value)
+            .is_equal_to_lines_with("one  \ntwo", LineOptions::new().ignoring_line_endings())
+            .is_equal_to_lines_with("one\ntwo",
+                                    LineOptions::new().ignoring_trailing_whitespace());
+    }
+
+    #[test]
+    fn should_split_crlf_lines_in_the_same_way_for_every_line_assertion() {
+        let value = "one\r\ntwo\r\n";
+        assert_that(&value)
+            .has_line_count(2)
+            .contains_line("two")
+            .is_equal_to_lines("one\r\ntwo\r\n")
+            .is_equal_to_lines_with("one\ntwo\n", LineOptions::new().ignoring_line_endings());
+        assert_that(&value).lines().has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with lines equal to the expected lines\
+                   \n\t but was: string differing from line <2>")]
+    fn should_count_trailing_empty_line_as_a_line() {
+        let value = "one\n\n";
+        assert_that(&value).has_line_count(2).is_equal_to_lines("one");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with lines equal to the expected lines \
+                   (ignoring line endings)\n\t but was: string differing from line <1>")]
+    fn should_describe_line_options_in_failure() {
+        let value = "one  \r\ntwo";
+        let options = LineOptions::new().ignoring_line_endings();
+        assert_that(&value).is_equal_to_lines_with("one\ntwo", options);
+    }
+
+    #[test]
+    fn should_return_parsed_value_if_str_parses() {
+        let value = "42";
+        assert_that(&value).parses_as::<u32>().is_greater_than(40).is_less_than(50);
+        assert_that(&" 42 ".trim().to_owned()).parses_as::<i64>().is_equal_to(42);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [port]\n\texpected: <\"4x2\"> to parse as <u16>\
+                   \n\t but was: Result[err] containing <ParseIntError { kind: InvalidDigit }>")]
+    fn should_panic_if_str_does_not_parse() {
+        let value = "4x2";
+        assert_that(&value).named("port").parses_as::<u16>();
+    }
+
+    #[test]
+    fn should_not_panic_if_string_does_not_parse_when_expected_not_to() {
+        let value = "yes".to_owned();
+        assert_that(&value).does_not_parse_as::<bool>();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <\"true\"> to not parse as <bool>\
+                   \n\t but was: Result[ok] containing <true>")]
+    fn should_panic_if_str_parses_when_expected_not_to() {
+        let value = "true";
+        assert_that(&value).does_not_parse_as::<bool>();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_length_and_char_count() {
+        let value = "héllo";
+        assert_that(&value).has_length(6).has_char_count(5);
+        assert_that(&value.to_owned()).not().has_char_count(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <5> byte(s)\n\t but was: <6> byte(s)")]
+    fn should_panic_if_str_does_not_have_length() {
+        let value = "héllo";
+        assert_that(&value).has_length(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <6> char(s)\n\t but was: <5> char(s)")]
+    fn should_panic_if_string_does_not_have_char_count() {
+        let value = "héllo".to_owned();
+        assert_that(&value).has_char_count(6);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn should_not_panic_if_str_has_grapheme_count() {
+        let value = "he\u{301}llo";
+        assert_that(&value).has_grapheme_count(5).has_char_count(6);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    #[should_panic(expected = "\n\texpected: string with <6> grapheme(s)\
+                   \n\t but was: <5> grapheme(s)")]
+    fn should_panic_if_string_does_not_have_grapheme_count() {
+        let value = "he\u{301}llo".to_owned();
+        assert_that(&value).has_grapheme_count(6);
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_in_character_class() {
+        assert_that(&"Hello, world!").is_ascii();
+        assert_that(&"héllo123").is_alphanumeric();
+        assert_that(&" \t\r\n").is_blank();
+        assert_that(&"").is_blank();
+        assert_that(&"HÉLLO, 123").is_uppercase();
+        assert_that(&"0123".to_owned()).contains_only_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an ASCII string\
+                   \n\t but was: <'é'> at char index <2> (byte index <2>)")]
+    fn should_panic_with_offending_char_if_str_is_not_ascii() {
+        let value = "caé";
+        assert_that(&value).is_ascii();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an alphanumeric string\
+                   \n\t but was: <'-'> at char index <2> (byte index <3>)")]
+    fn should_report_char_and_byte_index_separately() {
+        let value = "é1-2".to_owned();
+        assert_that(&value).is_alphanumeric();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a string containing only digits\
+                   \n\t but was: <'-'> at char index <3> (byte index <3>)")]
+    fn should_panic_if_str_does_not_contain_only_digits() {
+        let value = "123-456";
+        assert_that(&value).contains_only_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a blank string\
+                   \n\t but was: <'x'> at char index <1> (byte index <1>)")]
+    fn should_panic_if_string_is_not_blank() {
+        let value = " x ".to_owned();
+        assert_that(&value).is_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an uppercase string\
+                   \n\t but was: <'e'> at char index <1> (byte index <1>)")]
+    fn should_panic_if_str_is_not_uppercase() {
+        let value = "Hello";
+        assert_that(&value).is_uppercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a string which is not blank\n\t but was: <\"  \">")]
+    fn should_panic_if_str_is_blank_when_negated() {
+        let value = "  ";
+        assert_that(&value).not().is_blank();
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_not_panic_if_str_matches_regex() {
+        let value = "2017-06-01";
+        assert_that(&value).matches_regex(r"\d{4}-\d{2}-\d{2}");
+        assert_that(&value.to_owned()).matches_regex(r"\d{4}-\d{2}-\d{2}");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\texpected: string matching regex <\"a|ab\">\
+                   \n\t but was: <\"abc\">")]
+    fn should_panic_if_str_only_partially_matches_regex() {
+        let value = "abc";
+        assert_that(&value).matches_regex("a|ab");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_not_panic_if_str_does_not_match_regex() {
+        let value = "2017-06";
+        assert_that(&value).does_not_match_regex(r"\d{4}-\d{2}-\d{2}");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\texpected: string not matching regex <\"\\\\d+\">\
+                   \n\t but was: <\"2017\">")]
+    fn should_panic_if_string_matches_regex_when_not_expected_to() {
+        let value = "2017".to_owned();
+        assert_that(&value).does_not_match_regex(r"\d+");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_not_panic_if_str_contains_match() {
+        let value = "released on 2017-06-01";
+        assert_that(&value).contains_match(r"\d{4}");
+        assert_that(&value).not().contains_match("beta");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\texpected: string containing a match for regex <\"beta\">\
+                   \n\t but was: <\"released\">")]
+    fn should_panic_if_string_does_not_contain_match() {
+        let value = "released".to_owned();
+        assert_that(&value).contains_match("beta");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_return_named_captures_for_hashmap_assertions() {
+        let value = "2017-06-01";
+        assert_that(&value)
+            .captures(r"(?P<year>\d{4})-(?P<month>\d{2})(-(?P<week>W\d))?")
+            .has_length(2)
+            .contains_entry("year".to_string(), "2017".to_string())
+            .contains_entry("month".to_string(), "06".to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\tfor subject [date]\
+                   \n\texpected: string containing a match for regex <\"(?P<year>\\\\d{4})\">\
+                   \n\t but was: <\"June\">")]
+    fn should_panic_if_there_are_no_captures() {
+        let value = "June".to_owned();
+        assert_that(&value).named("date").captures(r"(?P<year>\d{4})");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\texpected: valid regex <\"(\">\n\t but was: regex parse error:")]
+    fn should_panic_if_regex_is_invalid() {
+        let value = "Hello";
+        assert_that(&value).contains_match("(");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_return_invalid_regex_as_failure_from_check_that() {
+        let error = check_that(&"Hello", |spec| {
+                spec.matches_regex("(");
+            })
+            .unwrap_err();
+
+        assert_that(&error.expected).contains_value("valid regex <\"(\">".to_string());
+    }
+
 }
@@ -0,0 +1,389 @@
+use super::{AssertionFailure, DescriptiveSpec, Spec};
+use super::numeric::CloseToSubject;
+
+use std::borrow::Borrow;
+use std::time::{Duration, Instant, SystemTime};
+
+pub trait DurationAssertions {
+    fn is_shorter_than<E: Borrow<Duration>>(&mut self, other: E) -> &mut Self;
+    fn is_longer_than<E: Borrow<Duration>>(&mut self, other: E) -> &mut Self;
+}
+
+impl CloseToSubject for Duration {
+    /// Asserts that the subject duration is within the tolerance of the expected duration, by
+    /// way of `Spec::is_close_to`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&elapsed).is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    /// ```
+    #[track_caller]
+    fn assert_close_to(spec: &mut Spec<Duration>, expected: &Duration, tolerance: &Duration) {
+        let subject = *spec.subject;
+        let difference = subject.abs_diff(*expected);
+
+        if !spec.passes(difference <= *tolerance) {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("duration close to <{:?}> (tolerance of <{:?}>)",
+                                       expected,
+                                       tolerance))
+                .with_negated_expected(format!("duration not close to <{:?}> (tolerance of <{:?}>)",
+                                               expected,
+                                               tolerance))
+                .with_actual(format!("<{:?}> (difference of <{:?}>)", subject, difference))
+                .fail();
+        }
+    }
+}
+
+impl<'s> DurationAssertions for Spec<'s, Duration> {
+    /// Asserts that the subject duration is shorter than the other duration.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&latency).is_shorter_than(Duration::from_millis(100));
+    /// ```
+    #[track_caller]
+    fn is_shorter_than<E: Borrow<Duration>>(&mut self, other: E) -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_other = *other.borrow();
+
+        if !self.passes(subject < borrowed_other) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("duration shorter than <{:?}>", borrowed_other))
+                .with_negated_expected(format!("duration not shorter than <{:?}>", borrowed_other))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject duration is longer than the other duration.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&timeout).is_longer_than(Duration::from_secs(1));
+    /// ```
+    #[track_caller]
+    fn is_longer_than<E: Borrow<Duration>>(&mut self, other: E) -> &mut Self {
+        let subject = *self.subject;
+        let borrowed_other = *other.borrow();
+
+        if !self.passes(subject > borrowed_other) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("duration longer than <{:?}>", borrowed_other))
+                .with_negated_expected(format!("duration not longer than <{:?}>", borrowed_other))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+}
+
+pub trait TimeAssertions<'s, T> {
+    fn is_before<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_after<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_within(&mut self, tolerance: Duration) -> Within<'_, 's, T>;
+}
+
+/// Checks that a time is within a tolerance of another time, once that time is given to `of`.
+///
+/// This is created by `is_within` on a `Spec` of an `Instant` or `SystemTime`.
+#[derive(Debug)]
+pub struct Within<'a, 's: 'a, T: 's> {
+    spec: &'a mut Spec<'s, T>,
+    tolerance: Duration,
+}
+
+impl<'s> TimeAssertions<'s, Instant> for Spec<'s, Instant> {
+    /// Asserts that the subject instant is before the other instant.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&started).is_before(finished);
+    /// ```
+    #[track_caller]
+    fn is_before<E: Borrow<Instant>>(&mut self, other: E) -> &mut Self {
+        let offset = Offset::between_instants(*self.subject, *other.borrow());
+        is_before(self, offset);
+
+        self
+    }
+
+    /// Asserts that the subject instant is after the other instant.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&finished).is_after(started);
+    /// ```
+    #[track_caller]
+    fn is_after<E: Borrow<Instant>>(&mut self, other: E) -> &mut Self {
+        let offset = Offset::between_instants(*self.subject, *other.borrow());
+        is_after(self, offset);
+
+        self
+    }
+
+    /// Asserts that the subject instant is within the tolerance of the instant given to `of`,
+    /// whether before or after it.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&fired).is_within(Duration::from_millis(20)).of(scheduled);
+    /// ```
+    fn is_within(&mut self, tolerance: Duration) -> Within<'_, 's, Instant> {
+        Within {
+            spec: self,
+            tolerance,
+        }
+    }
+}
+
+impl<'s> TimeAssertions<'s, SystemTime> for Spec<'s, SystemTime> {
+    /// Asserts that the subject system time is before the other system time.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&created).is_before(modified);
+    /// ```
+    #[track_caller]
+    fn is_before<E: Borrow<SystemTime>>(&mut self, other: E) -> &mut Self {
+        let offset = Offset::between_system_times(*self.subject, *other.borrow());
+        is_before(self, offset);
+
+        self
+    }
+
+    /// Asserts that the subject system time is after the other system time.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&modified).is_after(created);
+    /// ```
+    #[track_caller]
+    fn is_after<E: Borrow<SystemTime>>(&mut self, other: E) -> &mut Self {
+        let offset = Offset::between_system_times(*self.subject, *other.borrow());
+        is_after(self, offset);
+
+        self
+    }
+
+    /// Asserts that the subject system time is within the tolerance of the system time given
+    /// to `of`, whether before or after it.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&modified).is_within(Duration::from_secs(5)).of(SystemTime::now());
+    /// ```
+    fn is_within(&mut self, tolerance: Duration) -> Within<'_, 's, SystemTime> {
+        Within {
+            spec: self,
+            tolerance,
+        }
+    }
+}
+
+impl<'a, 's> Within<'a, 's, Instant> {
+    /// Asserts that the subject instant is within the tolerance of the expected instant.
+    #[track_caller]
+    pub fn of<E: Borrow<Instant>>(self, expected: E) -> &'a mut Spec<'s, Instant> {
+        let offset = Offset::between_instants(*self.spec.subject, *expected.borrow());
+        is_within(self.spec, self.tolerance, offset);
+
+        self.spec
+    }
+}
+
+impl<'a, 's> Within<'a, 's, SystemTime> {
+    /// Asserts that the subject system time is within the tolerance of the expected system time.
+    #[track_caller]
+    pub fn of<E: Borrow<SystemTime>>(self, expected: E) -> &'a mut Spec<'s, SystemTime> {
+        let offset = Offset::between_system_times(*self.spec.subject, *expected.borrow());
+        is_within(self.spec, self.tolerance, offset);
+
+        self.spec
+    }
+}
+
+/// How far the subject time is from the time it is being compared with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Offset {
+    Earlier(Duration),
+    Same,
+    Later(Duration),
+}
+
+impl Offset {
+    fn between_instants(subject: Instant, other: Instant) -> Offset {
+        if subject >= other {
+            Offset::later_by(subject.duration_since(other))
+        } else {
+            Offset::Earlier(other.duration_since(subject))
+        }
+    }
+
+    fn between_system_times(subject: SystemTime, other: SystemTime) -> Offset {
+        match subject.duration_since(other) {
+            Ok(difference) => Offset::later_by(difference),
+            Err(error) => Offset::Earlier(error.duration()),
+        }
+    }
+
+    fn later_by(difference: Duration) -> Offset {
+        if difference == Duration::from_secs(0) {
+            Offset::Same
+        } else {
+            Offset::Later(difference)
+        }
+    }
+
+    fn difference(self) -> Duration {
+        match self {
+            Offset::Earlier(difference) | Offset::Later(difference) => difference,
+            Offset::Same => Duration::from_secs(0),
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Offset::Earlier(difference) => format!("<{:?}> earlier", difference),
+            Offset::Same => "the same time".to_string(),
+            Offset::Later(difference) => format!("<{:?}> later", difference),
+        }
+    }
+}
+
+#[track_caller]
+fn is_before<'s, S: DescriptiveSpec<'s>>(spec: &'s S, offset: Offset) {
+    if !spec.passes(matches!(offset, Offset::Earlier(_))) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("time earlier than the expected time".to_string())
+            .with_negated_expected("time not earlier than the expected time".to_string())
+            .with_actual(offset.describe())
+            .fail();
+    }
+}
+
+#[track_caller]
+fn is_after<'s, S: DescriptiveSpec<'s>>(spec: &'s S, offset: Offset) {
+    if !spec.passes(matches!(offset, Offset::Later(_))) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("time later than the expected time".to_string())
+            .with_negated_expected("time not later than the expected time".to_string())
+            .with_actual(offset.describe())
+            .fail();
+    }
+}
+
+#[track_caller]
+fn is_within<'s, S: DescriptiveSpec<'s>>(spec: &'s S, tolerance: Duration, offset: Offset) {
+    if !spec.passes(offset.difference() <= tolerance) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("time within <{:?}> of the expected time", tolerance))
+            .with_negated_expected(format!("time not within <{:?}> of the expected time",
+                                           tolerance))
+            .with_actual(offset.describe())
+            .fail();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn should_not_panic_if_duration_is_close_to_expected() {
+        assert_that(&Duration::from_millis(1020))
+            .is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+        assert_that(&Duration::from_millis(980))
+            .is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: duration close to <1s> (tolerance of <50ms>)\
+                   \n\t but was: <1.5s> (difference of <500ms>)")]
+    fn should_panic_if_duration_is_not_close_to_expected() {
+        assert_that(&Duration::from_millis(1500))
+            .is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn should_not_panic_if_duration_is_shorter_or_longer() {
+        let latency = Duration::from_millis(20);
+
+        assert_that(&latency).is_shorter_than(Duration::from_millis(100));
+        assert_that(&latency).is_longer_than(Duration::from_micros(500));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: duration shorter than <100ms>\
+                   \n\t but was: <1.0000005s>")]
+    fn should_panic_if_duration_is_not_shorter_than() {
+        assert_that(&Duration::new(1, 500)).is_shorter_than(Duration::from_millis(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: duration not longer than <1s>\n\t but was: <2s>")]
+    fn should_panic_if_duration_is_longer_than_when_negated() {
+        assert_that(&Duration::from_secs(2)).not().is_longer_than(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn should_not_panic_if_instant_is_before_or_after() {
+        let started = Instant::now();
+        let finished = started + Duration::from_millis(250);
+
+        assert_that(&started).is_before(finished);
+        assert_that(&finished).is_after(started);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: time earlier than the expected time\
+                   \n\t but was: <250ms> later")]
+    fn should_panic_if_instant_is_not_before() {
+        let started = Instant::now();
+        let finished = started + Duration::from_millis(250);
+
+        assert_that(&finished).is_before(started);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: time later than the expected time\
+                   \n\t but was: the same time")]
+    fn should_panic_if_system_time_is_not_after() {
+        let time = UNIX_EPOCH + Duration::from_secs(100);
+        assert_that(&time).is_after(time);
+    }
+
+    #[test]
+    fn should_not_panic_if_time_is_within_tolerance() {
+        let scheduled = Instant::now();
+        let created = UNIX_EPOCH + Duration::from_secs(100);
+
+        assert_that(&(scheduled + Duration::from_millis(15)))
+            .is_within(Duration::from_millis(20))
+            .of(scheduled);
+        assert_that(&created)
+            .is_within(Duration::from_secs(5))
+            .of(created + Duration::from_secs(5))
+            .is_before(SystemTime::now());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: time within <5s> of the expected time\
+                   \n\t but was: <90s> earlier")]
+    fn should_panic_if_system_time_is_not_within_tolerance() {
+        let created = UNIX_EPOCH + Duration::from_secs(100);
+
+        assert_that(&created)
+            .is_within(Duration::from_secs(5))
+            .of(created + Duration::from_secs(90));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [fired]\
+                   \n\texpected: time not within <20ms> of the expected time\
+                   \n\t but was: <15ms> later")]
+    fn should_panic_if_instant_is_within_tolerance_when_negated() {
+        let scheduled = Instant::now();
+        let fired = scheduled + Duration::from_millis(15);
+
+        assert_that(&fired).named("fired").not().is_within(Duration::from_millis(20)).of(scheduled);
+    }
+}
@@ -1,8 +1,14 @@
-use super::{AssertionFailure, Spec};
+use super::{AssertionFailure, DescriptiveSpec, Spec};
+
+#[cfg(feature = "num")]
+use std::fmt::Debug;
+
+#[cfg(feature = "num")]
+use num::Float;
 
 pub trait VecAssertions {
-    fn has_length(&mut self, expected: usize);
-    fn is_empty(&mut self);
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
 }
 
 impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
@@ -12,14 +18,18 @@ impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
     /// ```rust,ignore
     /// assert_that(&vec![1, 2, 3, 4]).has_length(4);
     /// ```
-    fn has_length(&mut self, expected: usize) {
+    #[track_caller]
+    fn has_length(&mut self, expected: usize) -> &mut Self {
         let length = self.subject.len();
-        if length != expected {
+        if !self.passes(length == expected) {
             AssertionFailure::from_spec(self)
                 .with_expected(format!("vec to have length <{}>", expected))
+                .with_negated_expected(format!("vec to not have length <{}>", expected))
                 .with_actual(format!("<{}>", length))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject vector is empty. The subject type must be of `Vec`.
@@ -28,15 +38,215 @@ impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
     /// let test_vec: Vec<u8> = vec![];
     /// assert_that(&test_vec).is_empty();
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
+    #[track_caller]
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = &*self.subject;
 
-        if !subject.is_empty() {
+        if !self.passes(subject.is_empty()) {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("an empty vec"))
+                .with_expected("an empty vec".to_string())
+                .with_negated_expected("a non-empty vec".to_string())
                 .with_actual(format!("a vec with length <{:?}>", subject.len()))
                 .fail();
         }
+
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+pub trait FloatVecAssertions<T: Float> {
+    fn is_close_to_all<E: AsRef<[T]>>(&mut self, expected: E, tolerance: T) -> &mut Self;
+}
+
+#[cfg(feature = "num")]
+impl<'s, T: Float + Debug> FloatVecAssertions<T> for Spec<'s, Vec<T>> {
+    /// Asserts that each element of the subject vector is close to the element at the same
+    /// index of the expected slice, by the specified tolerance. The vector and slice must have
+    /// the same length.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1.0, 2.0]).is_close_to_all([1.0, 2.001], 0.01);
+    /// ```
+    #[track_caller]
+    fn is_close_to_all<E: AsRef<[T]>>(&mut self, expected: E, tolerance: T) -> &mut Self {
+        let expected = expected.as_ref();
+        let comparison = ElementComparison::of_slices(&self.subject, expected, tolerance);
+
+        is_close_to_all(self, expected, tolerance, comparison);
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+impl<'s, 'a, T: Float + Debug> FloatVecAssertions<T> for Spec<'s, &'a [T]> {
+    /// Asserts that each element of the subject slice is close to the element at the same
+    /// index of the expected slice, by the specified tolerance. The slices must have the same
+    /// length.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&&values[..]).is_close_to_all([1.0, 2.001], 0.01);
+    /// ```
+    #[track_caller]
+    fn is_close_to_all<E: AsRef<[T]>>(&mut self, expected: E, tolerance: T) -> &mut Self {
+        let expected = expected.as_ref();
+        let comparison = ElementComparison::of_slices(&self.subject, expected, tolerance);
+
+        is_close_to_all(self, expected, tolerance, comparison);
+        self
+    }
+}
+
+#[cfg(feature = "num")]
+pub trait FloatMatrixAssertions<T: Float> {
+    fn is_close_to_all<R, E>(&mut self, expected: E, tolerance: T) -> &mut Self
+        where R: AsRef<[T]>,
+              E: AsRef<[R]>;
+}
+
+#[cfg(feature = "num")]
+impl<'s, T: Float + Debug> FloatMatrixAssertions<T> for Spec<'s, Vec<Vec<T>>> {
+    /// Asserts that each element of the subject nested vector is close to the element at the
+    /// same row and column of the expected rows, by the specified tolerance. Every row must
+    /// have the same length as the expected row.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![vec![1.0, 0.0], vec![0.0, 1.0]])
+    ///     .is_close_to_all([[1.0, 0.0], [0.0, 1.0]], 1e-9);
+    /// ```
+    #[track_caller]
+    fn is_close_to_all<R, E>(&mut self, expected: E, tolerance: T) -> &mut Self
+        where R: AsRef<[T]>,
+              E: AsRef<[R]>
+    {
+        let expected: Vec<&[T]> = expected.as_ref().iter().map(AsRef::as_ref).collect();
+        let comparison = ElementComparison::of_rows(&self.subject, &expected, tolerance);
+
+        is_close_to_all(self, &expected, tolerance, comparison);
+        self
+    }
+}
+
+/// The outcome of comparing each element against the expected element at the same index.
+#[cfg(feature = "num")]
+struct ElementComparison<T> {
+    compared: usize,
+    out_of_tolerance: usize,
+    first_out_of_tolerance: Option<(String, T, T)>,
+    max_error: T,
+}
+
+#[cfg(feature = "num")]
+impl<T: Float + Debug> ElementComparison<T> {
+    fn new() -> ElementComparison<T> {
+        ElementComparison {
+            compared: 0,
+            out_of_tolerance: 0,
+            first_out_of_tolerance: None,
+            max_error: T::zero(),
+        }
+    }
+
+    /// Compares the slices, or returns a description of how their lengths differ.
+    fn of_slices(actual: &[T], expected: &[T], tolerance: T) -> Result<Self, String> {
+        if actual.len() != expected.len() {
+            return Err(format!("length <{}> rather than <{}>", actual.len(), expected.len()));
+        }
+
+        let mut comparison = ElementComparison::new();
+        for (index, (&actual, &expected)) in actual.iter().zip(expected).enumerate() {
+            comparison.compare(|| format!("[{}]", index), actual, expected, tolerance);
+        }
+
+        Ok(comparison)
+    }
+
+    /// Compares the rows, or returns a description of the first row whose length differs.
+    fn of_rows(actual: &[Vec<T>], expected: &[&[T]], tolerance: T) -> Result<Self, String> {
+        if actual.len() != expected.len() {
+            return Err(format!("<{}> row(s) rather than <{}>", actual.len(), expected.len()));
+        }
+
+        let mut comparison = ElementComparison::new();
+        for (row, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+            if actual.len() != expected.len() {
+                return Err(format!("row <{}> with length <{}> rather than <{}>",
+                                   row,
+                                   actual.len(),
+                                   expected.len()));
+            }
+
+            for (column, (&actual, &expected)) in actual.iter().zip(*expected).enumerate() {
+                comparison.compare(|| format!("[{}][{}]", row, column),
+                                   actual,
+                                   expected,
+                                   tolerance);
+            }
+        }
+
+        Ok(comparison)
+    }
+
+    fn compare<F>(&mut self, index: F, actual: T, expected: T, tolerance: T)
+        where F: FnOnce() -> String
+    {
+        let error = (actual - expected).abs();
+
+        self.compared += 1;
+        self.max_error = self.max_error.max(error);
+
+        if !(actual.is_finite() && error <= tolerance.abs()) {
+            self.out_of_tolerance += 1;
+            if self.first_out_of_tolerance.is_none() {
+                self.first_out_of_tolerance = Some((index(), actual, expected));
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.first_out_of_tolerance {
+            Some((ref index, actual, expected)) => {
+                format!("<{}> of <{}> element(s) out of tolerance, first at index {} with <{:?}> \
+                         rather than <{:?}>, max absolute error <{:?}>",
+                        self.out_of_tolerance,
+                        self.compared,
+                        index,
+                        actual,
+                        expected,
+                        self.max_error)
+            }
+            None => format!("max absolute error <{:?}>", self.max_error),
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+#[track_caller]
+fn is_close_to_all<'s, S, E, T>(spec: &Spec<'s, S>,
+                                expected: &E,
+                                tolerance: T,
+                                comparison: Result<ElementComparison<T>, String>)
+    where S: Debug,
+          E: Debug + ?Sized,
+          T: Float + Debug
+{
+    let close = comparison.as_ref().is_ok_and(|comparison| comparison.out_of_tolerance == 0);
+
+    if !spec.passes(close) {
+        let details = match comparison {
+            Ok(comparison) => comparison.describe(),
+            Err(shape) => shape,
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("all elements close to <{:?}> (tolerance of <{:?}>)",
+                                   expected,
+                                   tolerance))
+            .with_negated_expected(format!("not all elements close to <{:?}> (tolerance of <{:?}>)",
+                                           expected,
+                                           tolerance))
+            .with_actual(format!("<{:?}> ({})", spec.subject, details))
+            .fail();
     }
 }
 
@@ -71,4 +281,68 @@ mod tests {
         assert_that(&vec![1]).is_empty();
     }
 
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_not_panic_if_all_floats_are_close() {
+        let values = vec![1.0, 2.0, 3.0];
+
+        assert_that(&values).is_close_to_all([1.0, 2.001, 2.999], 0.01);
+        assert_that(&values).is_close_to_all(&values, 0.0);
+        assert_that(&&values[1..]).is_close_to_all(vec![2.0, 3.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(expected = "\n\texpected: all elements close to <[1.0, 2.0, 3.0]> \
+                   (tolerance of <0.01>)\n\t but was: <[1.0, 2.5, 4.0]> \
+                   (<2> of <3> element(s) out of tolerance, first at index [1] with <2.5> \
+                   rather than <2.0>, max absolute error <1.0>)")]
+    fn should_panic_if_floats_are_not_all_close() {
+        assert_that(&vec![1.0, 2.5, 4.0]).is_close_to_all([1.0, 2.0, 3.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(expected = "\n\t but was: <[1.0, NaN]> (<1> of <2> element(s) out of \
+                   tolerance, first at index [1] with <NaN> rather than <2.0>")]
+    fn should_panic_if_slice_contains_nan() {
+        let values = [1.0f32, f32::NAN];
+        assert_that(&&values[..]).is_close_to_all([1.0, 2.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(expected = "\n\t but was: <[1.0, 2.0]> (length <2> rather than <3>)")]
+    fn should_panic_if_float_vec_has_different_length() {
+        assert_that(&vec![1.0, 2.0]).is_close_to_all([1.0, 2.0, 3.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_not_panic_if_all_floats_in_rows_are_close() {
+        let identity = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+
+        assert_that(&identity).is_close_to_all([[1.0, 0.0], [0.0, 0.999]], 0.01);
+        assert_that(&identity).is_close_to_all(vec![vec![1.0, 0.0], vec![0.0, 1.0]], 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(expected = "\n\texpected: all elements close to <[[1.0, 0.0], [0.0, 1.0]]> \
+                   (tolerance of <0.01>)\n\t but was: <[[1.0, 0.0], [0.5, 1.0]]> \
+                   (<1> of <4> element(s) out of tolerance, first at index [1][0] with <0.5> \
+                   rather than <0.0>, max absolute error <0.5>)")]
+    fn should_panic_if_floats_in_rows_are_not_all_close() {
+        let matrix = vec![vec![1.0, 0.0], vec![0.5, 1.0]];
+        assert_that(&matrix).is_close_to_all([[1.0, 0.0], [0.0, 1.0]], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(expected = "\n\t but was: <[[1.0, 0.0], [0.0]]> \
+                   (row <1> with length <1> rather than <2>)")]
+    fn should_panic_if_row_has_different_length() {
+        let matrix = vec![vec![1.0, 0.0], vec![0.0]];
+        assert_that(&matrix).is_close_to_all([[1.0, 0.0], [0.0, 1.0]], 0.01);
+    }
 }